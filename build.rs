@@ -0,0 +1,39 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+// `<prefix><number><suffix>` names, like y2018 or day7.rs, in number order
+fn numbered(dir: &Path, prefix: &str, suffix: &str) -> Vec<usize> {
+    let mut numbers = fs::read_dir(dir).unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+        .filter_map(|name| name.strip_prefix(prefix)?.strip_suffix(suffix)?.parse::<usize>().ok())
+        .collect::<Vec<_>>();
+    numbers.sort_unstable();
+    numbers
+}
+
+// Every src/days/yYYYY/dayN.rs becomes a module and gets registered under its year, so adding a day
+// is just adding its file
+fn main() {
+    let days_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src").join("days");
+    println!("cargo:rerun-if-changed={}", days_dir.display());
+
+    let mut generated = String::new();
+    let mut all = vec![];
+    for year in numbered(&days_dir, "y", "") {
+        let year_dir = days_dir.join(format!("y{}", year));
+        generated += &format!("pub mod y{} {{\n", year);
+        for day in numbered(&year_dir, "day", ".rs") {
+            // Modules declared in included files don't resolve relative to src/days on their own
+            let path = year_dir.join(format!("day{}.rs", day));
+            generated += &format!("    #[path = {:?}]\n    pub mod day{};\n", path.display().to_string(), day);
+            all.push(format!("    ({0}, &y{0}::day{1}::Day{1}),\n", year, day));
+        }
+        generated += "}\n\n";
+    }
+    generated += "pub static ALL: &[(usize, &dyn crate::solver::AnySolver)] = &[\n";
+    generated += &all.concat();
+    generated += "];\n";
+
+    fs::write(Path::new(&env::var("OUT_DIR").unwrap()).join("days.rs"), generated).unwrap();
+}
@@ -0,0 +1,109 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::error::{Error, OnLine, Result};
+use crate::registry::{LAST_DAY, LEGACY_YEAR};
+
+pub const DEFAULT_PATH: &str = "answers.txt";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verification {
+    Verified,
+    Wrong { expected: String },
+    Unknown
+}
+
+// Known-good answers, one `<year> <day> <part> <answer>` per line
+pub struct Answers {
+    path: PathBuf,
+    answers: BTreeMap<(usize, usize, usize), String>
+}
+
+// Day 10 answers span several lines, so newlines (and the backslashes escaping them) are escaped
+pub fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+pub fn unescape(answer: &str) -> String {
+    let mut unescaped = String::new();
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => { unescaped.push('\n'); chars.next(); },
+            ('\\', Some('\\')) => { unescaped.push('\\'); chars.next(); },
+            (c, _) => unescaped.push(c)
+        }
+    }
+    unescaped
+}
+
+// The year a line starts with and the rest of it. Lines from before years were tracked start
+// with the day instead, and are LEGACY_YEAR's. Days only go up to LAST_DAY, so the two can't be mixed up
+pub fn split_year(line: &str) -> (usize, &str) {
+    match line.split_once(' ').map(|(first, rest)| (first.parse::<usize>(), rest)) {
+        Some((Ok(year), rest)) if year > LAST_DAY => (year, rest),
+        _ => (LEGACY_YEAR, line)
+    }
+}
+
+impl Answers {
+    pub fn load(path: impl AsRef<Path>) -> Result<Answers> {
+        let path = path.as_ref().to_path_buf();
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e.into())
+        };
+
+        let mut answers = BTreeMap::new();
+        for (i, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() { continue; }
+
+            let (year, rest) = split_year(line);
+            let mut fields = rest.splitn(3, ' ');
+            let day = fields.next().unwrap().parse().on_line(i + 1, line)?;
+            let part = fields.next().on_line(i + 1, line)?.parse().on_line(i + 1, line)?;
+            let answer = fields.next().on_line(i + 1, line)?;
+            if part != 1 && part != 2 {
+                return Err(Error::parse(i + 1, line, "part must be 1 or 2"));
+            }
+            answers.insert((year, day, part), unescape(answer));
+        }
+
+        Ok(Answers { path, answers })
+    }
+
+    pub fn get(&self, year: usize, day: usize, part: usize) -> Option<&str> {
+        self.answers.get(&(year, day, part)).map(|a| a.as_str())
+    }
+
+    pub fn set(&mut self, year: usize, day: usize, part: usize, answer: &str) {
+        self.answers.insert((year, day, part), answer.to_string());
+    }
+
+    pub fn verify(&self, year: usize, day: usize, part: usize, answer: &str) -> Verification {
+        match self.get(year, day, part) {
+            Some(expected) if expected == answer => Verification::Verified,
+            Some(expected) => Verification::Wrong { expected: expected.to_string() },
+            None => Verification::Unknown
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.answers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let contents = self.answers.iter()
+            .map(|((year, day, part), answer)| format!("{} {} {} {}\n", year, day, part, escape(answer)))
+            .collect::<String>();
+        fs::write(&self.path, contents)?;
+        Ok(())
+    }
+}
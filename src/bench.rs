@@ -0,0 +1,184 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::answers::split_year;
+use crate::error::{Error, OnLine, Result};
+use crate::params::Params;
+use crate::solver::AnySolver;
+
+pub const DEFAULT_BASELINE_PATH: &str = "bench.txt";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [Phase::Parse, Phase::Part1, Phase::Part2];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part1",
+            Phase::Part2 => "part2"
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Phase> {
+        Phase::ALL.iter().cloned().find(|p| p.name() == name)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let nanos = sorted.iter().map(|d| d.as_nanos() as f64).collect::<Vec<_>>();
+        let mean = nanos.iter().sum::<f64>() / nanos.len() as f64;
+        let variance = nanos.iter().map(|n| (n - mean) * (n - mean)).sum::<f64>() / nanos.len() as f64;
+
+        let median = if sorted.len() % 2 == 1 {
+            sorted[sorted.len() / 2]
+        } else {
+            (sorted[sorted.len() / 2 - 1] + sorted[sorted.len() / 2]) / 2
+        };
+
+        Stats {
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean as u64),
+            stddev: Duration::from_nanos(variance.sqrt() as u64)
+        }
+    }
+}
+
+pub struct DayBench {
+    pub day: usize,
+    pub title: &'static str,
+    pub phases: Vec<(Phase, Stats)>
+}
+
+fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+fn sample(warmup: usize, runs: usize, mut f: impl FnMut() -> Result<Duration>) -> Result<Stats> {
+    for _ in 0..warmup {
+        f()?;
+    }
+    let samples = (0..runs.max(1)).map(|_| f()).collect::<Result<Vec<_>>>()?;
+    Ok(Stats::from_samples(&samples))
+}
+
+pub fn bench(solver: &dyn AnySolver, input: &str, params: &Params, warmup: usize, runs: usize) -> Result<DayBench> {
+    let parse = sample(warmup, runs, || Ok(time(|| solver.parse(input, params)).1))?;
+
+    let parsed = solver.parse(input, params)?;
+    let part1 = sample(warmup, runs, || {
+        let (answer, elapsed) = time(|| solver.part1(parsed.as_ref()));
+        answer.map(|_| elapsed)
+    })?;
+    let part2 = sample(warmup, runs, || {
+        let (answer, elapsed) = time(|| solver.part2(parsed.as_ref()));
+        answer.map(|_| elapsed)
+    })?;
+
+    Ok(DayBench {
+        day: solver.day(),
+        title: solver.title(),
+        phases: vec![(Phase::Parse, parse), (Phase::Part1, part1), (Phase::Part2, part2)]
+    })
+}
+
+// Medians from an earlier run, one `<year> <day> <phase> <nanoseconds>` per line
+pub struct Baseline {
+    path: PathBuf,
+    medians: BTreeMap<(usize, usize, Phase), Duration>
+}
+
+impl Baseline {
+    pub fn load(path: impl AsRef<Path>) -> Result<Baseline> {
+        let path = path.as_ref().to_path_buf();
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e.into())
+        };
+
+        let mut medians = BTreeMap::new();
+        for (i, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() { continue; }
+
+            let (year, rest) = split_year(line.trim());
+            let fields = rest.split_whitespace().collect::<Vec<_>>();
+            if fields.len() != 3 {
+                return Err(Error::parse(i + 1, line, "expected `<year> <day> <phase> <nanoseconds>`"));
+            }
+            let day = fields[0].parse().on_line(i + 1, line)?;
+            let phase = Phase::from_name(fields[1]).on_line(i + 1, line)?;
+            let nanos = fields[2].parse().on_line(i + 1, line)?;
+            medians.insert((year, day, phase), Duration::from_nanos(nanos));
+        }
+
+        Ok(Baseline { path, medians })
+    }
+
+    pub fn get(&self, year: usize, day: usize, phase: Phase) -> Option<Duration> {
+        self.medians.get(&(year, day, phase)).cloned()
+    }
+
+    pub fn record(&mut self, year: usize, bench: &DayBench) {
+        for (phase, stats) in &bench.phases {
+            self.medians.insert((year, bench.day, *phase), stats.median);
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let contents = self.medians.iter()
+            .map(|((year, day, phase), median)| format!("{} {} {} {}\n", year, day, phase.name(), median.as_nanos()))
+            .collect::<String>();
+        fs::write(&self.path, contents)?;
+        Ok(())
+    }
+}
+
+// Relative change of the median against the baseline, e.g. 0.25 for 25% slower
+pub fn change(baseline: Duration, median: Duration) -> f64 {
+    (median.as_nanos() as f64 - baseline.as_nanos() as f64) / baseline.as_nanos().max(1) as f64
+}
+
+pub struct Elapsed(pub Duration);
+
+impl fmt::Display for Elapsed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let nanos = self.0.as_nanos() as f64;
+        let text = if nanos >= 1e9 {
+            format!("{:.2}s", nanos / 1e9)
+        } else if nanos >= 1e6 {
+            format!("{:.2}ms", nanos / 1e6)
+        } else if nanos >= 1e3 {
+            format!("{:.2}µs", nanos / 1e3)
+        } else {
+            format!("{}ns", nanos)
+        };
+        // Go through pad so tables can line these up
+        f.pad(&text)
+    }
+}
@@ -0,0 +1,22 @@
+use std::cell::OnceCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use crate::error::{ErrorKind, Result};
+
+// Threads can't be killed, so a day the runner gives up on is stopped by setting its flag.
+// Loops that can go on for a long time (or forever on a bad input) call check now and then
+thread_local! {
+    static CANCELLED: OnceCell<Arc<AtomicBool>> = const { OnceCell::new() };
+}
+
+// Makes check on this thread answer to `flag`, a thread only ever runs the one day
+pub fn install(flag: Arc<AtomicBool>) {
+    CANCELLED.with(|cancelled| cancelled.set(flag).expect("this thread already has a day"));
+}
+
+// Errors once this thread's day has been cancelled, threads without a flag never are
+pub fn check() -> Result<()> {
+    let cancelled = CANCELLED.with(|cancelled| cancelled.get().is_some_and(|flag| flag.load(Ordering::Relaxed)));
+    if cancelled { Err(ErrorKind::Cancelled.into()) } else { Ok(()) }
+}
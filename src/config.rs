@@ -0,0 +1,139 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::error::{Error, Result};
+use crate::output::Format;
+use crate::params::Params;
+
+pub const DEFAULT_PATH: &str = "aoc18.conf";
+
+// Defaults for the runner and per-day params, one `<key> = <value>` per line, like
+//
+//     # Run everything on 4 threads
+//     jobs = 4
+//     day7.workers = 2
+//     2018.day11.grid_size = 50
+//
+// Params without a year are for whichever year runs. Options on the command line win over anything in here
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    pub year: Option<usize>,
+    pub jobs: Option<usize>,
+    // In seconds, 0 to wait forever
+    pub timeout: Option<f64>,
+    pub format: Option<Format>,
+    // By year if one was given, then day
+    params: BTreeMap<(Option<usize>, usize), Params>,
+    // The same from --param, kept apart so they win over the file whether it gave a year or not
+    overrides: BTreeMap<(Option<usize>, usize), Params>
+}
+
+type Param = (Option<usize>, usize, String, u64);
+
+// `day7.workers=2` or `2018.day7.workers=2` into the year, the day, the name and the value
+fn param(key: &str, value: &str) -> std::result::Result<Param, String> {
+    let expected = || format!("expected a parameter like day7.workers or 2018.day7.workers, not {:?}", key);
+    let (year, rest) = match key.split_once('.') {
+        Some((year, rest)) if !year.starts_with("day") => {
+            (Some(year.parse().map_err(|_| format!("{:?} isn't a year", year))?), rest)
+        },
+        _ => (None, key)
+    };
+    let (day, name) = rest.strip_prefix("day").and_then(|rest| rest.split_once('.')).ok_or_else(expected)?;
+    let day = day.parse().map_err(|_| format!("{:?} isn't a day number", day))?;
+    let value = value.parse().map_err(|_| format!("{} takes a whole number, not {:?}", key, value))?;
+    Ok((year, day, name.to_string(), value))
+}
+
+impl Config {
+    pub fn load(path: impl AsRef<Path>) -> Result<Config> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Config::parse(&contents),
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(e.into())
+        }
+    }
+
+    pub fn parse(contents: &str) -> Result<Config> {
+        let mut config = Config::default();
+        for (i, line) in contents.lines().enumerate() {
+            let setting = line.split('#').next().unwrap().trim();
+            if setting.is_empty() { continue; }
+
+            let (key, value) = setting.split_once('=')
+                .ok_or_else(|| Error::parse(i + 1, line, "expected `<key> = <value>`"))?;
+            config.set(key.trim(), value.trim()).map_err(|reason| Error::parse(i + 1, line, reason))?;
+        }
+        Ok(config)
+    }
+
+    pub fn set(&mut self, key: &str, value: &str) -> std::result::Result<(), String> {
+        let not_a_number = || format!("{} takes a number, not {:?}", key, value);
+        match key {
+            "year" => self.year = Some(value.parse().map_err(|_| not_a_number())?),
+            "jobs" => self.jobs = Some(value.parse().map_err(|_| not_a_number())?),
+            "timeout" => self.timeout = Some(value.parse().map_err(|_| not_a_number())?),
+            "format" => self.format = Some(value.parse()?),
+            _ => {
+                let (year, day, name, value) = param(key, value)?;
+                self.params.entry((year, day)).or_default().set(&name, value);
+            }
+        }
+        Ok(())
+    }
+
+    // A `day7.workers=2` from the command line
+    pub fn set_param(&mut self, spec: &str) -> std::result::Result<(), String> {
+        let (key, value) = spec.split_once('=').ok_or_else(|| format!("expected a parameter like day7.workers=2, not {:?}", spec))?;
+        let (year, day, name, value) = param(key.trim(), value.trim())?;
+        self.overrides.entry((year, day)).or_default().set(&name, value);
+        Ok(())
+    }
+
+    // Only what was set, the day fills in its own defaults for the rest. The command line wins
+    // over the file, and within each params for the year win over ones for any year
+    pub fn params(&self, year: usize, day: usize) -> Params {
+        let mut params = Params::new();
+        for layer in &[&self.params, &self.overrides] {
+            for key in &[(None, day), (Some(year), day)] {
+                for (name, value) in layer.get(key).iter().flat_map(|p| p.iter()) {
+                    params.set(name, value);
+                }
+            }
+        }
+        params
+    }
+
+    pub fn all_params(&self, year: usize) -> BTreeMap<usize, Params> {
+        self.params.keys().chain(self.overrides.keys())
+            .filter(|&&(y, _)| y.is_none() || y == Some(year))
+            .map(|&(_, day)| (day, self.params(year, day)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn years_win_over_any_year() {
+        let config = Config::parse("day7.workers = 1\n2018.day7.workers = 3\n2019.day7.workers = 4").unwrap();
+        assert_eq!(config.params(2018, 7), Params::from_pairs(&[("workers", 3)]));
+        assert_eq!(config.params(2017, 7), Params::from_pairs(&[("workers", 1)]));
+        assert_eq!(config.params(2018, 6), Params::new());
+    }
+
+    #[test]
+    fn the_command_line_wins_over_the_file() {
+        let mut config = Config::parse("2018.day7.workers = 1\nday7.base_time = 0").unwrap();
+        config.set_param("day7.workers=2").unwrap();
+        assert_eq!(config.params(2018, 7), Params::from_pairs(&[("workers", 2), ("base_time", 0)]));
+        config.set_param("2018.day7.base_time=5").unwrap();
+        config.set_param("day7.base_time=6").unwrap();
+        assert_eq!(config.params(2018, 7), Params::from_pairs(&[("workers", 2), ("base_time", 5)]));
+        assert_eq!(config.all_params(2018).keys().collect::<Vec<_>>(), [&7]);
+    }
+}
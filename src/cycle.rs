@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::Sub;
+
+use crate::cancel;
+use crate::error::Result;
+
+// How a simulation repeats itself. The key function given alongside the step function splits
+// a state into a key, which is the same for states that repeat, and an offset that can drift
+// from one repeat to the next, like a pattern that keeps its shape but moves
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle<D> {
+    // Steps before the first state that repeats
+    pub start: usize,
+    pub period: usize,
+    // What the offset gains every period, zero for simulations that really do come back around
+    pub drift: D
+}
+
+// Steps until some state repeats. If no state ever does, this only stops once the day is cancelled
+pub fn find<S, K, D>(initial: S, step: impl FnMut(S) -> S, key: impl FnMut(&S) -> (K, D)) -> Result<Cycle<D>>
+    where K: Eq + Hash, D: Copy + Sub<Output=D>
+{
+    let (_, _, cycle) = run(initial, None, step, key)?;
+    Ok(cycle.unwrap())
+}
+
+// Like find, but gives up after `limit` steps
+pub fn find_within<S, K, D>(initial: S, limit: usize, step: impl FnMut(S) -> S, key: impl FnMut(&S) -> (K, D)) -> Result<Option<Cycle<D>>>
+    where K: Eq + Hash, D: Copy + Sub<Output=D>
+{
+    let (_, _, cycle) = run(initial, Some(limit), step, key)?;
+    Ok(cycle)
+}
+
+// The state after `steps` steps, skipping over every whole period once a cycle shows up.
+// shift moves a state along by `times` lots of drift
+pub fn fast_forward<S, K, D>(initial: S, steps: usize, mut step: impl FnMut(S) -> S, key: impl FnMut(&S) -> (K, D),
+                             shift: impl FnOnce(S, D, usize) -> S) -> Result<S>
+    where K: Eq + Hash, D: Copy + Sub<Output=D>
+{
+    let (mut state, mut done, cycle) = run(initial, Some(steps), &mut step, key)?;
+
+    if let Some(cycle) = cycle {
+        let times = (steps - done) / cycle.period;
+        state = shift(state, cycle.drift, times);
+        done += times * cycle.period;
+    }
+
+    // Whatever is left of the last period
+    for _ in done..steps {
+        cancel::check()?;
+        state = step(state);
+    }
+    Ok(state)
+}
+
+// Steps until a state repeats or the limit is hit, returning the state it stopped at,
+// how many steps that took and the cycle if there was one
+fn run<S, K, D>(initial: S, limit: Option<usize>, mut step: impl FnMut(S) -> S, mut key: impl FnMut(&S) -> (K, D)) -> Result<(S, usize, Option<Cycle<D>>)>
+    where K: Eq + Hash, D: Copy + Sub<Output=D>
+{
+    let mut seen = HashMap::new();
+    let mut state = initial;
+    let mut n = 0;
+    while limit.is_none_or(|limit| n < limit) {
+        cancel::check()?;
+        let (k, offset) = key(&state);
+        if let Some(&(then, then_offset)) = seen.get(&k) {
+            let cycle = Cycle { start: then, period: n - then, drift: offset - then_offset };
+            return Ok((state, n, Some(cycle)));
+        }
+        seen.insert(k, (n, offset));
+
+        state = step(state);
+        n += 1;
+    }
+    Ok((state, n, None))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, 2, then round 3, 4, 5, 6 forever
+    fn tail_then_loop(x: usize) -> usize {
+        if x < 3 { x + 1 } else { 3 + (x - 2) % 4 }
+    }
+
+    fn exact(x: &usize) -> (usize, usize) {
+        (*x, 0)
+    }
+
+    #[test]
+    fn finds_where_the_loop_starts() {
+        assert_eq!(find(0, tail_then_loop, exact).unwrap(), Cycle { start: 3, period: 4, drift: 0 });
+        assert_eq!(find(5, tail_then_loop, exact).unwrap(), Cycle { start: 0, period: 4, drift: 0 });
+    }
+
+    #[test]
+    fn find_within_gives_up() {
+        assert_eq!(find_within(0, 5, |x| (x + 1) % 10, exact).unwrap(), None);
+        assert_eq!(find_within(0, 20, |x| (x + 1) % 10, exact).unwrap(), Some(Cycle { start: 0, period: 10, drift: 0 }));
+        // Never repeats at all
+        assert_eq!(find_within(0, 1000, |x| x + 1, exact).unwrap(), None);
+    }
+
+    #[test]
+    fn fast_forward_lands_where_stepping_does() {
+        for steps in 0..40 {
+            let stepped = (0..steps).fold(0, |x, _| tail_then_loop(x));
+            let skipped = fast_forward(0, steps, tail_then_loop, exact, |x, _, _| x).unwrap();
+            assert_eq!(skipped, stepped, "after {} steps", steps);
+        }
+    }
+
+    #[test]
+    fn fast_forward_adds_up_the_drift() {
+        // (phase, position): the phase goes round 0, 1, 2 and the position moves on by it,
+        // so every period of 3 moves it along by 3
+        let step = |(phase, position): (isize, isize)| ((phase + 1) % 3, position + phase);
+        // Only the phase has to match, the position is what drifts
+        let key = |&(phase, position): &(isize, isize)| (phase, position);
+        let shift = |(phase, position): (isize, isize), drift: isize, times: usize| (phase, position + drift * times as isize);
+
+        for steps in 0..30 {
+            let stepped = (0..steps).fold((0, 100), |s, _| step(s));
+            assert_eq!(fast_forward((0, 100), steps, step, key, shift).unwrap(), stepped, "after {} steps", steps);
+        }
+        assert_eq!(find((0, 100), step, key).unwrap(), Cycle { start: 0, period: 3, drift: 3 });
+    }
+}
@@ -0,0 +1,3 @@
+// One module per src/days/yYYYY, with one per dayN.rs in it, plus ALL with every solver and its year
+// in year and day order, see build.rs
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
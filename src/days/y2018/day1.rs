@@ -0,0 +1,158 @@
+use std::collections::HashSet;
+
+use crate::cancel;
+use crate::error::{Error, OnLine, Result};
+use crate::examples::Example;
+use crate::random::Rng;
+
+solver!(Day1, 1, "Chronal Calibration", Vec<isize>, generate: size = 1000 "frequency changes");
+
+pub fn parse(input: &str) -> Result<Vec<isize>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| line.parse::<isize>().on_line(i + 1, line))
+        .collect()
+}
+
+pub fn part1(changes: &[isize]) -> Result<String> {
+    Ok(changes.iter().sum::<isize>().to_string())
+}
+
+// Every pass goes through the first pass's frequencies moved along by the total change, so one only
+// comes round again if two of those (the start included) are a whole number of totals apart
+fn ever_repeats(changes: &[isize]) -> bool {
+    let total = changes.iter().sum::<isize>();
+    if total == 0 { return true; }
+
+    let mut offsets = HashSet::new();
+    let mut acc: isize = 0;
+    // The last frequency of a pass is the first of the next one, so it's left out
+    for change in changes {
+        if !offsets.insert(acc.rem_euclid(total)) { return true; }
+        acc += change;
+    }
+    false
+}
+
+pub fn part2(changes: &[isize]) -> Result<String> {
+    if changes.is_empty() || !ever_repeats(changes) { return Err(Error::no_solution()); }
+
+    let mut acc = 0;
+
+    // The starting frequency counts as reached too
+    let mut seen = HashSet::new();
+    seen.insert(acc);
+
+    loop {
+        cancel::check()?;
+        for change in changes {
+            acc += change;
+
+            if seen.contains(&acc) {
+                return Ok(acc.to_string());
+            }
+            seen.insert(acc);
+        }
+    }
+}
+
+// The frequency drifts by less than there are changes, so some frequency always comes up twice
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut changes = (1..size).map(|_| rng.signed(-20, 20)).collect::<Vec<_>>();
+    let drift = rng.signed(-(size as isize - 1), size as isize - 1);
+    changes.push(drift - changes.iter().sum::<isize>());
+    changes.iter().map(|c| format!("{:+}\n", c)).collect()
+}
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        input: "
++1
+-2
++3
++1",
+        part1: Some("3"),
+        part2: Some("2"),
+        params: &[]
+    },
+    Example {
+        input: "
++1
++1
++1",
+        part1: Some("3"),
+        part2: None,
+        params: &[]
+    },
+    Example {
+        input: "
++1
++1
+-2",
+        part1: Some("0"),
+        part2: None,
+        params: &[]
+    },
+    Example {
+        input: "
+-1
+-2
+-3",
+        part1: Some("-6"),
+        part2: None,
+        params: &[]
+    },
+    Example {
+        input: "
++1
+-1",
+        part1: None,
+        part2: Some("0"),
+        params: &[]
+    },
+    Example {
+        input: "
++3
++3
++4
+-2
+-4",
+        part1: None,
+        part2: Some("10"),
+        params: &[]
+    },
+    Example {
+        input: "
+-6
++3
++8
++5
+-6",
+        part1: None,
+        part2: Some("5"),
+        params: &[]
+    },
+    Example {
+        input: "
++7
++7
+-2
+-7
+-4",
+        part1: None,
+        part2: Some("14"),
+        params: &[]
+    },
+    // Not from the puzzle. The starting 0 comes round before -1 does, so it has to count as reached
+    Example {
+        input: "
+-1
++2
+-1",
+        part1: Some("0"),
+        part2: Some("0"),
+        params: &[]
+    }
+];
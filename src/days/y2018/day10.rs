@@ -0,0 +1,187 @@
+use crate::cancel;
+use crate::error::{Error, OnLine, Result};
+use crate::examples::Example;
+use crate::geometry::{Bounds, Point, Vector};
+use crate::grid::Grid;
+use crate::parsing;
+use crate::random::Rng;
+
+solver!(Day10, 10, "The Stars Align", Points, generate: size = 8 "letters in the message");
+
+#[derive(Clone)]
+struct Star {
+    position: Point,
+    velocity: Vector
+}
+
+#[derive(Clone)]
+pub struct Points {
+    points: Vec<Star>
+}
+
+impl Points {
+    fn from_str(s: &str) -> Result<Points> {
+        let points = s.lines().enumerate().map(|(i, line)| {
+            let (x, y, vx, vy) = parsing::scan("position=<{},{}> velocity=<{},{}>", line).on_line(i + 1, line)?;
+            Ok(Star { position: Point::new(x, y), velocity: Vector::new(vx, vy) })
+        }).collect::<Result<Vec<_>>>()?;
+        if points.is_empty() { return Err(Error::no_solution()); }
+        Ok(Points { points })
+    }
+
+    fn step(&mut self) {
+        self.points.iter_mut().for_each(|p| p.position += p.velocity);
+    }
+
+    fn bounds(&self) -> Bounds {
+        Bounds::of(self.points.iter().map(|p| p.position)).unwrap()
+    }
+
+    // The message is wherever the points are closest together, after that they drift apart again
+    fn align(&mut self) -> Result<usize> {
+        let mut steps = 0;
+        loop {
+            cancel::check()?;
+            let mut next = self.clone();
+            next.step();
+            if next.bounds().height() >= self.bounds().height() { return Ok(steps); }
+
+            *self = next;
+            steps += 1;
+        }
+    }
+}
+
+pub fn parse(input: &str) -> Result<Points> {
+    Points::from_str(input)
+}
+
+pub fn part1(points: &Points) -> Result<String> {
+    let mut points = points.clone();
+    points.align()?;
+
+    let bounds = points.bounds();
+    // With a blank row below the message
+    let mut display = Grid::new(bounds.width(), bounds.height() + 1, false);
+    for p in &points.points {
+        let offset = p.position - bounds.min;
+        display[(offset.x as usize, offset.y as usize)] = true;
+    }
+
+    Ok(format!("\n{}", display.render(|&lit| if lit { '#' } else { ' ' })))
+}
+
+pub fn part2(points: &Points) -> Result<String> {
+    Ok(points.clone().align()?.to_string())
+}
+
+// A random pattern, 10 rows high like the real letters, that the stars only line up into at one moment.
+// The height over time is convex, and the stars given opposite vertical speeds on the top and bottom rows
+// make it shrink right up until that moment and grow right after
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let (width, height) = (size.max(1) * 8 - 2, 10);
+    let mut pixels = vec![];
+    for y in 0..height {
+        for x in 0..width {
+            let in_letter = x % 8 < 6;
+            if in_letter && rng.chance(45) { pixels.push((x as isize, y)); }
+        }
+    }
+    let mut stars = vec![
+        ((0, 0), -1), ((1, 0), 1),
+        ((0, height - 1), 1), ((1, height - 1), -1)
+    ];
+    stars.extend(pixels.into_iter().map(|pixel| (pixel, rng.signed(-5, 5))));
+
+    let time = rng.signed(5000, 15000);
+    let offset = (rng.signed(-50, 50), rng.signed(-50, 50));
+    let mut lines = stars.into_iter()
+        .map(|((x, y), vy)| {
+            let vx = rng.signed(-5, 5);
+            let (px, py) = (x + offset.0 - vx * time, y + offset.1 - vy * time);
+            format!("position=<{:>6}, {:>6}> velocity=<{:>2}, {:>2}>\n", px, py, vx, vy)
+        })
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut lines);
+    lines.concat()
+}
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        input: "
+position=< 9,  1> velocity=< 0,  2>
+position=< 7,  0> velocity=<-1,  0>
+position=< 3, -2> velocity=<-1,  1>
+position=< 6, 10> velocity=<-2, -1>
+position=< 2, -4> velocity=< 2,  2>
+position=<-6, 10> velocity=< 2, -2>
+position=< 1,  8> velocity=< 1, -1>
+position=< 1,  7> velocity=< 1,  0>
+position=<-3, 11> velocity=< 1, -2>
+position=< 7,  6> velocity=<-1, -1>
+position=<-2,  3> velocity=< 1,  0>
+position=<-4,  3> velocity=< 2,  0>
+position=<10, -3> velocity=<-1,  1>
+position=< 5, 11> velocity=< 1, -2>
+position=< 4,  7> velocity=< 0, -1>
+position=< 8, -2> velocity=< 0,  1>
+position=<15,  0> velocity=<-2,  0>
+position=< 1,  6> velocity=< 1,  0>
+position=< 8,  9> velocity=< 0, -1>
+position=< 3,  3> velocity=<-1,  1>
+position=< 0,  5> velocity=< 0, -1>
+position=<-2,  2> velocity=< 2,  0>
+position=< 5, -2> velocity=< 1,  2>
+position=< 1,  4> velocity=< 2,  1>
+position=<-2,  7> velocity=< 2, -2>
+position=< 3,  6> velocity=<-1, -1>
+position=< 5,  0> velocity=< 1,  0>
+position=<-6,  0> velocity=< 2,  0>
+position=< 5,  9> velocity=< 1, -2>
+position=<14,  7> velocity=<-2,  0>
+position=<-3,  6> velocity=< 2, -1>",
+        // Rendered with a blank first line and a blank padding row below the message
+        part1: Some(concat!(
+            "\n#   #  ###",
+            "\n#   #   # ",
+            "\n#   #   # ",
+            "\n#####   # ",
+            "\n#   #   # ",
+            "\n#   #   # ",
+            "\n#   #   # ",
+            "\n#   #  ###",
+            "\n          "
+        )),
+        part2: Some("3"),
+        params: &[]
+    },
+    // Not from the puzzle. The points are within 10 rows of each other two steps before they line up,
+    // which is where they used to be taken as aligned
+    Example {
+        input: "
+position=< 0, -5> velocity=< 0,  1>
+position=< 1,  5> velocity=< 0, -1>
+position=< 0, -2> velocity=< 0,  1>
+position=< 1,  8> velocity=< 0, -1>",
+        part1: Some(concat!(
+            "\n##",
+            "\n  ",
+            "\n  ",
+            "\n##",
+            "\n  "
+        )),
+        part2: Some("5"),
+        params: &[]
+    },
+    // Not from the puzzle. A message taller than 10 rows, which used to have no solution
+    Example {
+        input: "
+position=< 0, -3> velocity=< 0,  1>
+position=< 1,  3> velocity=< 0, -1>
+position=< 0,  8> velocity=< 0,  1>
+position=< 1, 14> velocity=< 0, -1>",
+        part1: None,
+        part2: Some("3"),
+        params: &[]
+    }
+];
@@ -0,0 +1,106 @@
+use crate::cancel;
+use crate::error::{Error, OnLine, Result};
+use crate::examples::Example;
+use crate::geometry::{Bounds, Point, Vector};
+use crate::grid::Grid;
+use crate::params::Params;
+use crate::random::Rng;
+
+solver!(Day11, 11, "Chronal Charge", SumGrid, params: [grid_size = 300 "width and height of the grid"],
+    generate: size = 0 "nothing, it's just a serial number");
+
+// Each cell holds the sum of everything above and to the left of it, itself included
+pub struct SumGrid {
+    grid: Grid<isize>
+}
+
+impl SumGrid {
+    fn new(grid: &Grid<isize>) -> SumGrid {
+        let mut sums = Grid::new(grid.width(), grid.height(), 0);
+        for (x, y) in grid.positions() {
+            let get = |dx: usize, dy: usize| {
+                if x < dx || y < dy { return 0 }
+                sums[(x - dx, y - dy)]
+            };
+            sums[(x, y)] = grid[(x, y)] + get(0, 1) + get(1, 0) - get(1, 1);
+        }
+        SumGrid { grid: sums }
+    }
+
+    fn size(&self) -> usize {
+        self.grid.width()
+    }
+
+    fn value(&self, x: usize, y: usize, w: usize, h: usize) -> isize {
+        let a = if x > 0 && y > 0 { self.grid[(x-1, y-1)] } else { 0 };
+        let b = if y > 0 { self.grid[(x+w-1, y-1)] } else { 0 };
+        let c = if x > 0 { self.grid[(x-1, y+h-1)] } else { 0 };
+        let d = self.grid[(x+w-1, y+h-1)];
+        d + a - b - c
+    }
+}
+
+pub fn power(x: usize, y: usize, serial: usize) -> isize {
+    let rack_id = (x+1) + 10;
+    let mut power = rack_id * (y+1);
+    power += serial;
+    power *= rack_id;
+    ((power / 100) % 10) as isize - 5
+}
+
+// The top left corner of the best window, counting from 0
+pub fn find_power_rect(sg: &SumGrid, window_size: usize) -> (Point, isize) {
+    let last = (sg.size() - window_size) as isize;
+    Bounds { min: Point::new(0, 0), max: Point::new(last, last) }.points()
+        .map(|p| (p, sg.value(p.x as usize, p.y as usize, window_size, window_size)))
+        .max_by_key(|(_, power)| *power)
+        .unwrap()
+}
+
+pub fn parse(input: &str, params: &Params) -> Result<SumGrid> {
+    let serial = input.trim().parse().on_line(1, input.trim())?;
+    // Part 1 looks at 3x3 windows, so anything smaller has nothing to find
+    let size = params.get("grid_size") as usize;
+    if size < 3 { return Err(Error::no_solution()); }
+
+    let grid = Grid::from_fn(size, size, |(x, y)| power(x, y, serial));
+    Ok(SumGrid::new(&grid))
+}
+
+pub fn part1(grid: &SumGrid) -> Result<String> {
+    let (corner, _) = find_power_rect(grid, 3);
+    Ok((corner + Vector::new(1, 1)).to_string())
+}
+
+pub fn part2(grid: &SumGrid) -> Result<String> {
+    let mut best = None;
+    for size in 1..=grid.size() {
+        cancel::check()?;
+        let (corner, power) = find_power_rect(grid, size);
+        // Ties go to the bigger window
+        if best.is_none_or(|(_, _, best)| power >= best) {
+            best = Some((size, corner, power));
+        }
+    }
+    let (size, corner, _) = best.unwrap();
+    Ok(format!("{},{}", corner + Vector::new(1, 1), size))
+}
+
+fn generate(rng: &mut Rng, _size: usize) -> String {
+    format!("{}\n", rng.range(1, 9999))
+}
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        input: "18",
+        part1: Some("33,45"),
+        part2: Some("90,269,16"),
+        params: &[]
+    },
+    Example {
+        input: "42",
+        part1: Some("21,61"),
+        part2: Some("232,251,12"),
+        params: &[]
+    }
+];
@@ -0,0 +1,149 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use crate::cycle;
+use crate::error::{Error, OnLine, Result};
+use crate::examples::Example;
+use crate::params::Params;
+use crate::parsing;
+use crate::random::Rng;
+
+solver!(Day12, 12, "Subterranean Sustainability", Pots, params: [
+    generations = 50_000_000_000 "generations part 2 looks ahead"
+], generate: size = 100 "pots in the initial state");
+
+pub struct Pots {
+    rules: [bool; 32],
+    initial_state: HashSet<isize>,
+    // How far part 2 looks ahead
+    generations: usize
+}
+
+pub fn parse_rules<'a>(lines: impl Iterator<Item=(usize, &'a str)>) -> Result<[bool; 32]> {
+    let mut arr = [false; 32];
+    for (line_no, line) in lines {
+        let (pattern, result): (String, char) = parsing::scan("{} => {}", line.trim()).on_line(line_no, line)?;
+        if pattern.len() != 5 || !pattern.chars().chain(Some(result)).all(|c| c == '#' || c == '.') {
+            return Err(Error::parse(line_no, line, "expected a rule like `..#.# => #`"));
+        }
+
+        let bits = pattern.chars()
+            .enumerate()
+            .filter(|&(_, c)| c == '#')
+            .fold(0, |a, (i, _)| a | (1 << i));
+        arr[bits] = result == '#';
+    }
+
+    Ok(arr)
+}
+
+fn get_window_bits(state: &HashSet<isize>, index: isize) -> usize {
+    (0..5)
+        .filter(|i| state.contains(&(index-2+i)))
+        .fold(0, |a, i| a | (1 << i))
+}
+
+fn step(state: HashSet<isize>, rules: &[bool; 32]) -> HashSet<isize> {
+    if state.is_empty() { return state; }
+    let bounds = (state.iter().min().unwrap() - 2, state.iter().max().unwrap() + 2);
+    (bounds.0..=bounds.1)
+        .filter(|i| rules[get_window_bits(&state, *i)])
+        .collect()
+}
+
+pub fn parse(input: &str, params: &Params) -> Result<Pots> {
+    let mut lines = input.lines().enumerate().map(|(i, l)| (i + 1, l));
+    let (_, first_line) = lines.next().on_line(1, "")?;
+    let initial_state = first_line.strip_prefix("initial state: ").on_line(1, first_line)?.trim();
+    lines.next();
+    let rules = parse_rules(lines.filter(|(_, s)| !s.is_empty()))?;
+
+    let initial_state: HashSet<isize> = initial_state.chars()
+        .enumerate()
+        .filter(|(_, c)| *c == '#')
+        .map(|(i, _)| i as isize)
+        .collect();
+
+    Ok(Pots { rules, initial_state, generations: params.get("generations") as usize })
+}
+
+pub fn part1(pots: &Pots) -> Result<String> {
+    let mut current_state = pots.initial_state.clone();
+    for _ in 0..20 {
+        current_state = step(current_state, &pots.rules);
+    }
+
+    Ok(current_state.iter().sum::<isize>().to_string())
+}
+
+// Where the leftmost plant is, and every plant relative to it
+fn shape(state: &HashSet<isize>) -> (Vec<isize>, isize) {
+    let leftmost = state.iter().min().cloned().unwrap_or(0);
+    let mut aligned = state.iter().map(|x| x - leftmost).collect::<Vec<isize>>();
+    aligned.sort_unstable();
+    (aligned, leftmost)
+}
+
+pub fn part2(pots: &Pots) -> Result<String> {
+    // The plants settle into a pattern that keeps its shape but slides along
+    let state = cycle::fast_forward(pots.initial_state.clone(), pots.generations, |state| step(state, &pots.rules), shape,
+        |state, drift, times| state.iter().map(|x| x + drift * times as isize).collect())?;
+
+    Ok(state.iter().sum::<isize>().to_string())
+}
+
+fn pots(plants: impl Iterator<Item=bool>) -> String {
+    plants.map(|plant| if plant { '#' } else { '.' }).collect()
+}
+
+// Rules are rolled until the plants settle into a pattern that slides along, which part 2 relies on.
+// Empty pots next to empty pots stay empty, or the row would fill up forever
+fn generate(rng: &mut Rng, size: usize) -> String {
+    loop {
+        let initial = (0..size.max(1)).map(|_| rng.chance(50)).collect::<Vec<_>>();
+        let mut rules = [false; 32];
+        for rule in rules.iter_mut().skip(1) {
+            *rule = rng.chance(40);
+        }
+
+        let state = (0..initial.len() as isize).filter(|&i| initial[i as usize]).collect::<HashSet<_>>();
+        let settled = match cycle::find_within(state.clone(), 300, |s| step(s, &rules), shape).ok().flatten() {
+            Some(settled) => settled,
+            None => continue
+        };
+        // A row that dies out is a cycle too, but not much of a puzzle
+        let repeating = (0..settled.start).fold(state, |s, _| step(s, &rules));
+        if repeating.is_empty() { continue; }
+
+        let mut text = format!("initial state: {}\n\n", pots(initial.into_iter()));
+        for (pattern, &result) in rules.iter().enumerate() {
+            let _ = writeln!(text, "{} => {}", pots((0..5).map(|i| pattern & (1 << i) != 0)), if result { '#' } else { '.' });
+        }
+        return text;
+    }
+}
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        input: "
+initial state: #..#.#..##......###...###
+
+...## => #
+..#.. => #
+.#... => #
+.#.#. => #
+.#.## => #
+.##.. => #
+.#### => #
+#.#.# => #
+#.### => #
+##.#. => #
+##.## => #
+###.. => #
+###.# => #
+####. => #",
+        part1: Some("325"),
+        part2: None,
+        params: &[]
+    }
+];
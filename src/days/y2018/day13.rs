@@ -0,0 +1,221 @@
+use std::collections::HashSet;
+
+use crate::cancel;
+use crate::error::{Error, Result};
+use crate::examples::Example;
+use crate::geometry::{Direction, Point};
+use crate::grid::Grid;
+use crate::random::Rng;
+
+solver!(Day13, 13, "Mine Cart Madness", Track, generate: size = 150 "width and height of the tracks");
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Cart {
+    id: usize,
+    position: Point,
+    direction: Direction,
+    step: usize
+}
+
+impl Cart {
+    fn new(id: usize, position: Point, direction: Direction) -> Cart {
+        Cart {
+            id,
+            position,
+            direction,
+            step: 0
+        }
+    }
+
+    fn step(&mut self) {
+        self.position = self.position.step(self.direction);
+    }
+
+    fn flip_maybe(&mut self, track: char) {
+        self.direction = match (track, self.direction) {
+            ('/', Direction::Up) | ('/', Direction::Down) => self.direction.turn_right(),
+            ('/', _) => self.direction.turn_left(),
+            ('\\', Direction::Up) | ('\\', Direction::Down) => self.direction.turn_left(),
+            ('\\', _) => self.direction.turn_right(),
+            ('+', d) => {
+                let d = match self.step % 3 {
+                    0 => d.turn_left(),
+                    1 => d,
+                    2 => d.turn_right(),
+                    _ => unreachable!()
+                };
+                self.step += 1;
+                d
+            },
+            (_, d) => d
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Track {
+    map: Grid<char>,
+    carts: Vec<Cart>
+}
+
+impl Track {
+    fn new(input: &str) -> Result<Track> {
+        // Ragged lines just have empty space after them
+        let map = Grid::parse(input, Some)?;
+
+        let mut carts = Vec::new();
+        for (position, c) in map.iter() {
+            if let Some(direction) = Direction::from_arrow(*c) {
+                carts.push(Cart::new(carts.len(), Point::from(position), direction));
+            }
+        }
+
+        Ok(Track { map, carts })
+    }
+
+    fn step(&mut self) -> Result<Vec<Point>> {
+        self.carts.sort_unstable_by_key(|c| c.position);
+
+        let mut collisions = Vec::new();
+        let mut dead_carts = HashSet::new();
+        for i in 0..self.carts.len() {
+            if dead_carts.contains(&self.carts[i].id) { continue; }
+
+            let cart_mut = self.carts.get_mut(i).unwrap();
+            cart_mut.step();
+            // Carts only leave the map if the track has a loose end
+            let track = self.map.at(cart_mut.position).ok_or_else(Error::no_solution)?;
+            cart_mut.flip_maybe(*track);
+
+            if let Some(other) = self.carts.iter()
+                .find(|c| c.position == self.carts[i].position && c.id != self.carts[i].id && !dead_carts.contains(&c.id)) {
+                    collisions.push(other.position);
+                    dead_carts.insert(other.id);
+                    dead_carts.insert(self.carts[i].id);
+            }
+        }
+
+        self.carts.retain(|c| !dead_carts.contains(&c.id));
+
+        Ok(collisions)
+    }
+}
+
+// Whether the crashes leave exactly one cart within that many ticks, so part 2 has an answer
+fn settles_within(track: &Track, ticks: usize) -> bool {
+    let mut track = track.clone();
+    for _ in 0..ticks {
+        if track.carts.len() <= 1 { break; }
+        if track.step().is_err() { return false; }
+    }
+    track.carts.len() == 1
+}
+
+pub fn parse(input: &str) -> Result<Track> {
+    Track::new(input)
+}
+
+pub fn part1(track: &Track) -> Result<String> {
+    let mut track = track.clone();
+    if track.carts.len() < 2 { return Err(Error::no_solution()); }
+
+    loop {
+        cancel::check()?;
+        let collisions = track.step()?;
+        if let Some(collision) = collisions.first() {
+            return Ok(collision.to_string());
+        }
+    }
+}
+
+pub fn part2(track: &Track) -> Result<String> {
+    let mut track = track.clone();
+
+    while track.carts.len() > 1 {
+        cancel::check()?;
+        track.step()?;
+    }
+
+    // An even number of carts can all crash into each other
+    let last_cart = track.carts.first().ok_or_else(Error::no_solution)?;
+    Ok(last_cart.position.to_string())
+}
+
+// Rectangles of track that only ever cross at right angles, with an odd number of carts on them.
+// Layouts are rolled until the carts crash down to one, since carts that never meet would keep part 2 going forever
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let side = size.max(8);
+    loop {
+        let mut map = vec![vec![' '; side]; side];
+        for _ in 0..side / 5 + 1 {
+            let (w, h) = (rng.range(4, side / 2), rng.range(4, side / 2));
+            let (x, y) = (rng.range(0, side - w), rng.range(0, side - h));
+
+            let mut border = vec![((x, y), '/'), ((x + w - 1, y), '\\'), ((x, y + h - 1), '\\'), ((x + w - 1, y + h - 1), '/')];
+            for i in x + 1..x + w - 1 {
+                border.push(((i, y), '-'));
+                border.push(((i, y + h - 1), '-'));
+            }
+            for j in y + 1..y + h - 1 {
+                border.push(((x, j), '|'));
+                border.push(((x + w - 1, j), '|'));
+            }
+
+            // Straight pieces can cross the other way round, anything else would be ambiguous
+            let fits = border.iter().all(|&((i, j), c)| matches!((map[j][i], c), (' ', _) | ('|', '-') | ('-', '|')));
+            if !fits { continue; }
+            for ((i, j), c) in border {
+                map[j][i] = if map[j][i] == ' ' { c } else { '+' };
+            }
+        }
+
+        let mut straights = (0..side).flat_map(|j| (0..side).map(move |i| (i, j)))
+            .filter(|&(i, j)| map[j][i] == '-' || map[j][i] == '|')
+            .collect::<Vec<_>>();
+        rng.shuffle(&mut straights);
+        let carts = rng.range(1, side / 10 + 1) * 2 + 1;
+        if straights.len() < carts { continue; }
+        for &(i, j) in &straights[..carts] {
+            map[j][i] = match (map[j][i], rng.chance(50)) {
+                ('-', true) => '>',
+                ('-', false) => '<',
+                (_, true) => 'v',
+                (_, false) => '^'
+            };
+        }
+
+        let text = map.iter().map(|row| row.iter().collect::<String>() + "\n").collect::<String>();
+        match parse(&text) {
+            Ok(ref track) if settles_within(track, 100_000) => return text,
+            _ => continue
+        }
+    }
+}
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        input: r"
+/->-\        
+|   |  /----\
+| /-+--+-\  |
+| | |  | v  |
+\-+-/  \-+--/
+  \------/   ",
+        part1: Some("7,3"),
+        part2: None,
+        params: &[]
+    },
+    Example {
+        input: r"
+/>-<\  
+|   |  
+| /<+-\
+| | | v
+\>+</ |
+  |   ^
+  \<->/",
+        part1: None,
+        part2: Some("6,4"),
+        params: &[]
+    }
+];
@@ -0,0 +1,134 @@
+use crate::cancel;
+use crate::error::{Error, OnLine, Result};
+use crate::examples::Example;
+use crate::random::Rng;
+
+solver!(Day14, 14, "Chocolate Charts", String, generate: size = 6 "digits");
+
+fn step(elf1: &mut usize, elf2: &mut usize, scores: &mut Vec<u8>) {
+    let new_score = scores[*elf1] + scores[*elf2];
+    if new_score > 9 {
+        scores.push(new_score / 10);
+        scores.push(new_score % 10);
+    } else {
+        scores.push(new_score);
+    }
+
+    *elf1 = (*elf1 + 1 + scores[*elf1] as usize) % scores.len();
+    *elf2 = (*elf2 + 1 + scores[*elf2] as usize) % scores.len();
+}
+
+pub fn parse(input: &str) -> Result<String> {
+    let input = input.trim();
+    if input.is_empty() || !input.chars().all(|c| c.is_ascii_digit()) {
+        return Err(Error::parse(1, input, "expected a number"));
+    }
+    Ok(input.to_string())
+}
+
+pub fn part1(input: &str) -> Result<String> {
+    let mut elf1 = 0;
+    let mut elf2 = 1;
+
+    let mut scores = vec![3, 7];
+
+    let count = input.parse::<usize>().on_line(1, input)?;
+    for steps in 0usize.. {
+        if scores.len() >= count + 10 { break; }
+        // Steps are tiny, checking on every one of them shows up in the timings
+        if steps % 4096 == 0 { cancel::check()?; }
+        step(&mut elf1, &mut elf2, &mut scores);
+    }
+    Ok(scores[count..count+10].iter().map(|x| x.to_string()).collect())
+}
+
+pub fn part2(input: &str) -> Result<String> {
+    let mut elf1 = 0;
+    let mut elf2 = 1;
+
+    let mut scores = vec![3, 7];
+
+    let input_chars = input
+        .chars()
+        .map(|x| x.to_digit(10).unwrap() as u8)
+        .collect::<Vec<_>>();
+
+    for steps in 0usize.. {
+        if steps % 4096 == 0 { cancel::check()?; }
+        step(&mut elf1, &mut elf2, &mut scores);
+
+        if scores.len() >= input_chars.len() && scores[scores.len() - input_chars.len()..] == input_chars[..] {
+            break;
+        }
+
+        if scores.len() > input_chars.len() && scores[scores.len() - input_chars.len() - 1..scores.len() - 1] == input_chars[..] {
+            scores.pop();
+            break;
+        }
+    }
+
+    Ok((scores.len() - input_chars.len()).to_string())
+}
+
+// Digits from somewhere on the scoreboard, since plenty of sequences take ages to show up or never do.
+// Part 1 makes that many recipes, so a lot more than the real 6 digits won't finish
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.clamp(1, 9);
+    let at = rng.range(0, 10usize.pow(size as u32).min(1_000_000));
+    let (mut elf1, mut elf2, mut scores) = (0, 1, vec![3, 7]);
+    while scores.len() < at + size {
+        step(&mut elf1, &mut elf2, &mut scores);
+    }
+    scores[at..at + size].iter().map(|score| score.to_string()).collect::<String>() + "\n"
+}
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        input: "9",
+        part1: Some("5158916779"),
+        part2: None,
+        params: &[]
+    },
+    Example {
+        input: "5",
+        part1: Some("0124515891"),
+        part2: None,
+        params: &[]
+    },
+    Example {
+        input: "18",
+        part1: Some("9251071085"),
+        part2: None,
+        params: &[]
+    },
+    Example {
+        input: "2018",
+        part1: Some("5941429882"),
+        part2: None,
+        params: &[]
+    },
+    Example {
+        input: "51589",
+        part1: None,
+        part2: Some("9"),
+        params: &[]
+    },
+    Example {
+        input: "01245",
+        part1: None,
+        part2: Some("5"),
+        params: &[]
+    },
+    Example {
+        input: "92510",
+        part1: None,
+        part2: Some("18"),
+        params: &[]
+    },
+    Example {
+        input: "59414",
+        part1: None,
+        part2: Some("2018"),
+        params: &[]
+    }
+];
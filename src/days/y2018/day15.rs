@@ -0,0 +1,367 @@
+use std::collections::HashSet;
+
+use crate::cancel;
+use crate::error::Result;
+use crate::examples::Example;
+use crate::geometry::Point;
+use crate::search;
+use crate::grid::Grid;
+use crate::params::Params;
+use crate::random::Rng;
+
+solver!(Day15, 15, "Beverage Bandits", Map, params: [
+    hit_points = 200 "what every unit starts with",
+    attack_power = 3 "attack power of every unit, part 2 raises the elves'"
+], generate: size = 32 "width and height of the cave");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Space,
+    Tile
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum UnitType {
+    Goblin,
+    Elf
+}
+
+impl UnitType {
+    fn enemy(&self) -> UnitType {
+        match self {
+            UnitType::Goblin => UnitType::Elf,
+            UnitType::Elf => UnitType::Goblin
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Unit {
+    position: Point,
+    ap: usize,
+    hp: isize,
+    ty: UnitType
+}
+
+#[derive(Clone)]
+pub struct Map {
+    cells: Grid<Cell>,
+    units: Vec<Unit>,
+    // What every unit starts with, before part 2 makes the elves stronger
+    attack_power: usize
+}
+
+impl Map {
+    fn new(input: &str, hp: isize, ap: usize) -> Result<Map> {
+        let map = Grid::parse(input, |c| if "#.EG".contains(c) { Some(c) } else { None })?;
+
+        let mut units = vec![];
+        for (position, c) in map.iter() {
+            let position = Point::from(position);
+            if *c == 'E' { units.push(Unit { position, ty: UnitType::Elf, hp, ap }); }
+            if *c == 'G' { units.push(Unit { position, ty: UnitType::Goblin, hp, ap }); }
+        }
+        let cells = map.map(|&c| if c == '#' { Cell::Tile } else { Cell::Space });
+        Ok(Map { cells, units, attack_power: ap })
+    }
+
+    // The squares next to pos that aren't walls, occupied or not
+    fn open_neighbors(&self, pos: Point) -> impl Iterator<Item=Point> + '_ {
+        pos.neighbors4().filter(move |&p| self.cells.at(p) == Some(&Cell::Space))
+    }
+
+    fn with_elf_ap(&self, elf_ap: usize) -> Map {
+        let mut map = self.clone();
+        map.units.iter_mut()
+            .filter(|u| u.ty == UnitType::Elf)
+            .for_each(|u| u.ap = elf_ap);
+        map
+    }
+
+    fn unit_at(&self, pos: Point) -> Option<usize> {
+        (0..self.units.len())
+            .filter(|id| self.units[*id].position == pos)
+            .find(|id| self.units[*id].hp > 0)
+    }
+
+    // The squares next to pos that a unit could step onto
+    fn free_neighbors(&self, pos: Point) -> impl Iterator<Item=Point> + '_ {
+        self.open_neighbors(pos).filter(move |&p| self.unit_at(p).is_none())
+    }
+
+    fn enemies_of(&self, ty: UnitType) -> Vec<usize> {
+        (0..self.units.len())
+            .filter(|id| self.units[*id].ty == ty.enemy())
+            .filter(|id| self.units[*id].hp > 0)
+            .collect()
+    }
+
+    fn step(&mut self) -> bool {
+        // For instance, the order in which units take their turns within a round
+        // is the reading order of their starting positions in that round, regardless
+        // of the type of unit or whether other units have moved after the round started.        
+        let mut unit_ids_sorted = (0..self.units.len()).collect::<Vec<_>>();
+        unit_ids_sorted.sort_unstable_by_key(|id| self.units[*id].position);
+
+        for unit_id in unit_ids_sorted {
+            if self.units[unit_id].hp <= 0 { continue; }
+
+            // Each unit begins its turn by identifying all possible targets (enemy units). 
+            let enemies = self.enemies_of(self.units[unit_id].ty);
+            // If no targets remain, combat ends.
+            if enemies.is_empty() { return false; }
+
+            // If the unit is already in range of a target, it does not move, but continues its turn with an attack.
+            if self.do_attack(unit_id) { continue; }
+
+            // Otherwise, since it is not in range of a target, it moves.
+            
+            // Then, the unit identifies all of the open squares (.) that are in range of each target;
+            let target_squares = enemies
+                .iter()
+                // these are the squares which are adjacent (immediately up, down, left, or right) to any target
+                // and which aren't already occupied by a wall
+                .flat_map(|id| self.open_neighbors(self.units[*id].position))
+                // or another unit.
+                .filter(|p| self.unit_at(*p).is_none())
+                .collect::<HashSet<_>>();
+            let position = self.units[unit_id].position;
+
+            // If ... there are no open squares which are in range of a target, the unit ends its turn.
+            if target_squares.is_empty() { continue; }
+
+            // If multiple squares are in range and tied for being the fewest steps away, the square which is first in reading order is chosen.
+            let chosen_square = match search::nearest(position, |&p| self.free_neighbors(p), |p| target_squares.contains(p)) {
+                Some((square, _)) => square,
+                None => continue
+            };
+
+            // The unit then takes a single step toward the chosen square along the shortest path to that square.
+            let from_chosen = search::bfs(chosen_square, |&p| self.free_neighbors(p));
+            let move_to_square = self.free_neighbors(position)
+                .flat_map(|p| from_chosen.distance(&p).map(|d| (p, d)))
+                // If multiple steps would put the unit equally closer to its destination, the unit chooses the step which is first in reading order.
+                .min_by(|(p1, d1), (p2, d2)| d1.cmp(d2).then(p1.cmp(p2)));
+
+            self.units[unit_id].position = move_to_square.unwrap().0;
+
+            // After moving ..., the unit attacks.
+            self.do_attack(unit_id);
+        }
+
+        //self.units.retain(|u| u.hp > 0);
+
+        true
+    }
+
+    fn do_attack(&mut self, unit_id: usize) -> bool {
+        // To attack, the unit first determines all of the targets that are in range of it by being immediately adjacent to it.
+        let adjacant_enemies = self.open_neighbors(self.units[unit_id].position)
+            .flat_map(|p| self.unit_at(p))
+            .filter(|id| self.units[*id].ty == self.units[unit_id].ty.enemy())
+            .collect::<Vec<_>>();
+        
+        // If there are no such targets, the unit ends its turn.
+        if adjacant_enemies.is_empty() { return false; }
+
+        // Otherwise, the adjacent target with the fewest hit points is selected
+        let target = adjacant_enemies.into_iter()
+            .min_by(|i1, i2| self.units[*i1].hp.cmp(&self.units[*i2].hp)
+                // in a tie, the adjacent target with the fewest hit points which is first in reading order is selected.
+                .then(self.units[*i1].position.cmp(&self.units[*i2].position)))
+            .unwrap();
+
+        // The unit deals damage equal to its attack power to the selected target, reducing its hit points by that amount.
+        self.units[target].hp -= self.units[unit_id].ap as isize;
+        true
+    }
+
+    fn total_hp(&self) -> usize {
+        self.units.iter()
+            .map(|u| u.hp)
+            .filter(|hp| *hp > 0)
+            .sum::<isize>() as usize
+    }
+
+    fn is_any_dead(&self, ty: UnitType) -> bool {
+        self.units.iter().filter(|u| u.ty == ty).any(|u| u.hp <= 0)
+    }
+}
+
+pub fn parse(input: &str, params: &Params) -> Result<Map> {
+    Map::new(input, params.get("hit_points") as isize, params.get("attack_power") as usize)
+}
+
+pub fn part1(map: &Map) -> Result<String> {
+    let mut map = map.clone();
+
+    let mut rounds = 0;
+    while map.step() {
+        cancel::check()?;
+        rounds += 1;
+    }
+
+    let outcome = rounds * map.total_hp();
+    Ok(outcome.to_string())
+}
+
+pub fn part2(initial_map: &Map) -> Result<String> {
+    let mut power_level = initial_map.attack_power + 1;
+
+    loop {
+        let mut map = initial_map.with_elf_ap(power_level);
+        let mut rounds = 0;
+        while map.step() && !map.is_any_dead(UnitType::Elf) {
+            cancel::check()?;
+            rounds += 1;
+        }
+
+        if !map.is_any_dead(UnitType::Elf) {
+            return Ok((rounds * map.total_hp()).to_string());
+        }
+
+        power_level += 1;
+    }
+}
+
+// A walled cave with rocks scattered around, where everything but the biggest open area is filled in
+// so every unit can reach every other. Combat in a cave split in two would never end
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let side = size.max(5);
+    loop {
+        let mut open = vec![vec![false; side]; side];
+        for row in open.iter_mut().take(side - 1).skip(1) {
+            for cell in row.iter_mut().take(side - 1).skip(1) {
+                *cell = rng.chance(70);
+            }
+        }
+
+        let mut biggest = vec![];
+        let mut seen = HashSet::new();
+        for start in (0..side).flat_map(|y| (0..side).map(move |x| (x, y))) {
+            if !open[start.1][start.0] || !seen.insert(start) { continue; }
+            let mut area = vec![];
+            let mut stack = vec![start];
+            while let Some((x, y)) = stack.pop() {
+                area.push((x, y));
+                for (nx, ny) in [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)] {
+                    if open[ny][nx] && seen.insert((nx, ny)) { stack.push((nx, ny)); }
+                }
+            }
+            if area.len() > biggest.len() { biggest = area; }
+        }
+        if biggest.len() < 2 { continue; }
+
+        let mut map = vec![vec!['#'; side]; side];
+        for &(x, y) in &biggest {
+            map[y][x] = '.';
+        }
+        rng.shuffle(&mut biggest);
+        let units = (biggest.len() / 25).max(2);
+        for (i, &(x, y)) in biggest[..units].iter().enumerate() {
+            // The first two make sure there's one of each
+            map[y][x] = match i {
+                0 => 'E',
+                1 => 'G',
+                _ => if rng.chance(35) { 'E' } else { 'G' }
+            };
+        }
+        return map.iter().map(|row| row.iter().collect::<String>() + "\n").collect();
+    }
+}
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        input: "
+#######
+#.G...#
+#...EG#
+#.#.#G#
+#..G#E#
+#.....#
+#######",
+        part1: Some("27730"),
+        part2: Some("4988"),
+        params: &[]
+    },
+    Example {
+        input: "
+#######
+#G..#E#
+#E#E.E#
+#G.##.#
+#...#E#
+#...E.#
+#######",
+        part1: Some("36334"),
+        part2: None,
+        params: &[]
+    },
+    Example {
+        input: "
+#######
+#E..EG#
+#.#G.E#
+#E.##E#
+#G..#.#
+#..E#.#
+#######",
+        part1: Some("39514"),
+        part2: Some("31284"),
+        params: &[]
+    },
+    Example {
+        input: "
+#######
+#E.G#.#
+#.#G..#
+#G.#.G#
+#G..#.#
+#...E.#
+#######",
+        part1: Some("27755"),
+        part2: Some("3478"),
+        params: &[]
+    },
+    Example {
+        input: "
+#######
+#.E...#
+#.#..G#
+#.###.#
+#E#G#G#
+#...#G#
+#######",
+        part1: Some("28944"),
+        part2: Some("6474"),
+        params: &[]
+    },
+    Example {
+        input: "
+#########
+#G......#
+#.E.#...#
+#..##..G#
+#...##..#
+#...#...#
+#.G...G.#
+#.....G.#
+#########",
+        part1: Some("18740"),
+        part2: Some("1140"),
+        params: &[]
+    },
+    // Not from the puzzle. Here the closest squares in range of a target are sometimes the furthest
+    // a unit can reach, which used to leave it standing still
+    Example {
+        input: "
+#####
+#..G#
+#...#
+#E#.#
+#####",
+        part1: Some("136"),
+        part2: Some("2550"),
+        params: &[]
+    }
+];
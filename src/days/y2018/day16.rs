@@ -0,0 +1,256 @@
+use std::fmt::Write;
+
+use lazy_static::lazy_static;
+
+use crate::error::{Error, OnLine, Result};
+use crate::examples::Example;
+use crate::parsing;
+use crate::random::Rng;
+
+solver!(Day16, 16, "Chronal Classification", Manual, generate: size = 800 "samples, and instructions in the program");
+
+pub type Manual = (Vec<TestCase>, Vec<[usize; 4]>);
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Opcode {
+    Addr,
+    Addi,
+    Mulr,
+    Muli,
+    Banr,
+    Bani,
+    Borr,
+    Bori,
+    Setr,
+    Seti,
+    Gtir,
+    Gtri,
+    Gtrr,
+    Eqir,
+    Eqri,
+    Eqrr,
+}
+
+impl Opcode {
+    // None if an operand names a register that doesn't exist
+    pub fn execute(&self, mut regs: [usize; 4], a: usize, b: usize, c: usize) -> Option<[usize; 4]> {
+        let reg = |i: usize| regs.get(i).copied();
+        let value = match self {
+            Opcode::Addr => reg(a)? + reg(b)?,
+            Opcode::Addi => reg(a)? + b,
+            Opcode::Mulr => reg(a)? * reg(b)?,
+            Opcode::Muli => reg(a)? * b,
+            Opcode::Banr => reg(a)? & reg(b)?,
+            Opcode::Bani => reg(a)? & b,
+            Opcode::Borr => reg(a)? | reg(b)?,
+            Opcode::Bori => reg(a)? | b,
+            Opcode::Setr => reg(a)?,
+            Opcode::Seti => a,
+            Opcode::Gtir => if a > reg(b)? { 1 } else { 0 },
+            Opcode::Gtri => if reg(a)? > b { 1 } else { 0 },
+            Opcode::Gtrr => if reg(a)? > reg(b)? { 1 } else { 0 },
+            Opcode::Eqir => if a == reg(b)? { 1 } else { 0 },
+            Opcode::Eqri => if reg(a)? == b { 1 } else { 0 },
+            Opcode::Eqrr => if reg(a)? == reg(b)? { 1 } else { 0 }
+        };
+        *regs.get_mut(c)? = value;
+        Some(regs)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TestCase {
+    input_registers: [usize; 4],
+    opcode: usize,
+    a: usize,
+    b: usize,
+    c: usize,
+    output_registers: [usize; 4],
+}
+
+lazy_static! {
+    pub static ref ALL_OPCODES: [Opcode; 16] = [Opcode::Addr, Opcode::Addi, Opcode::Mulr, Opcode::Muli, Opcode::Banr, Opcode::Bani, Opcode::Borr, Opcode::Bori, Opcode::Setr, Opcode::Seti, Opcode::Gtir, Opcode::Gtri, Opcode::Gtrr, Opcode::Eqir, Opcode::Eqri, Opcode::Eqrr];
+}
+
+fn parse_numbers(pattern: &str, line_no: usize, line: &str) -> Result<[usize; 4]> {
+    let (a, b, c, d) = parsing::scan(pattern, line).on_line(line_no, line)?;
+    Ok([a, b, c, d])
+}
+
+fn parse_instruction(line_no: usize, line: &str) -> Result<[usize; 4]> {
+    let instruction = parse_numbers("{} {} {} {}", line_no, line)?;
+    if instruction[0] >= ALL_OPCODES.len() {
+        return Err(Error::parse(line_no, line, "opcode numbers go up to 15"));
+    }
+    Ok(instruction)
+}
+
+// `line_no` is the line the case starts on
+fn parse_case(line_no: usize, case: &str) -> Result<TestCase> {
+    match case.split('\n').collect::<Vec<_>>().as_slice() {
+        [before, input, after] => {
+            let [opcode, a, b, c] = parse_instruction(line_no + 1, input)?;
+            Ok(TestCase {
+                input_registers: parse_numbers("Before: [{}, {}, {}, {}]", line_no, before)?,
+                opcode, a, b, c,
+                output_registers: parse_numbers("After:  [{}, {}, {}, {}]", line_no + 2, after)?,
+            })
+        }
+        _ => Err(Error::parse(line_no, case.lines().next().unwrap_or(""), "expected a Before/instruction/After sample"))
+    }
+}
+
+pub fn matching_opcodes(case: &TestCase) -> Vec<Opcode> {
+    let mut matching_opcodes = vec![];
+    for opcode in ALL_OPCODES.iter() {
+        let output = opcode.execute(case.input_registers, case.a, case.b, case.c);
+        if output == Some(case.output_registers) {
+            matching_opcodes.push(*opcode);
+        }
+    }
+    matching_opcodes
+}
+
+pub fn parse(input: &str) -> Result<Manual> {
+    // Samples all start with their Before line, whatever else is there is the program
+    let mut test_cases = vec![];
+    let mut program = vec![];
+    for (line_no, block) in parsing::blocks(input) {
+        if block.starts_with("Before:") {
+            test_cases.push(parse_case(line_no, block)?);
+            continue;
+        }
+
+        for (i, code_line) in block.lines().enumerate() {
+            program.push(parse_instruction(line_no + i, code_line)?);
+        }
+    }
+
+    Ok((test_cases, program))
+}
+
+pub fn part1((test_cases, _): &Manual) -> Result<String> {
+    let mut cases_over_3 = 0;
+    for case in test_cases.iter() {
+        let matching_opcode_count = matching_opcodes(case).len();
+        if matching_opcode_count >= 3 {
+            cases_over_3 += 1;
+        }
+    }
+
+    Ok(cases_over_3.to_string())
+}
+
+pub fn part2((cases, program): &Manual) -> Result<String> {
+    let mut opcode_possibilities: Vec<Vec<Opcode>> = vec![];
+    for _ in 0..16 {
+        opcode_possibilities.push(ALL_OPCODES.to_vec());
+    }
+
+    for case in cases.iter() {
+        if opcode_possibilities[case.opcode].len() == 1 { continue; }
+
+        let matches = matching_opcodes(case);
+        opcode_possibilities[case.opcode].retain(|op| matches.contains(op));
+    }
+
+    while !opcode_possibilities.iter().all(|op| op.len() == 1) {
+        let remaining = opcode_possibilities.iter().map(|op| op.len()).sum::<usize>();
+
+        for op_a in 0..16 {
+            if opcode_possibilities[op_a].len() == 1 {
+                let the_opcode_to_remove = opcode_possibilities[op_a][0];
+                for (op_b, possibilities) in opcode_possibilities.iter_mut().enumerate() {
+                    if op_a == op_b { continue; }
+                    possibilities.retain(|&o| o != the_opcode_to_remove);
+                }
+            }
+        }
+
+        // The samples don't narrow every opcode down to exactly one
+        if opcode_possibilities.iter().map(|op| op.len()).sum::<usize>() == remaining {
+            return Err(Error::no_solution());
+        }
+    }
+
+    let opcodes = opcode_possibilities.iter().flatten().collect::<Vec<_>>();
+    let mut registers = [0usize; 4];
+
+    for &[opcode, a, b, c] in program {
+        registers = opcodes[opcode].execute(registers, a, b, c).ok_or_else(Error::no_solution)?;
+    }
+    Ok(registers[0].to_string())
+}
+
+// Whether the samples pin every opcode down, the way part 2 works it out
+fn pinned_down(samples: &[([usize; 4], [usize; 4], [usize; 4])]) -> bool {
+    let mut possible = vec![ALL_OPCODES.to_vec(); 16];
+    for (before, instruction, after) in samples {
+        let [opcode, a, b, c] = *instruction;
+        possible[opcode].retain(|op| op.execute(*before, a, b, c) == Some(*after));
+    }
+    loop {
+        let known = possible.iter().filter(|ops| ops.len() == 1).map(|ops| ops[0]).collect::<Vec<_>>();
+        let before = possible.iter().map(|ops| ops.len()).sum::<usize>();
+        for ops in possible.iter_mut().filter(|ops| ops.len() > 1) {
+            ops.retain(|op| !known.contains(op));
+        }
+        let after = possible.iter().map(|ops| ops.len()).sum::<usize>();
+        if after == 16 { return possible.iter().all(|ops| ops.len() == 1); }
+        if after == before { return false; }
+    }
+}
+
+// Samples of a random numbering of the opcodes, added to until they pin it down, then a program
+// that keeps its registers small so nothing overflows
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut numbering: Vec<Opcode> = ALL_OPCODES.to_vec();
+    rng.shuffle(&mut numbering);
+    let random_registers = |rng: &mut Rng| [rng.range(0, 3), rng.range(0, 3), rng.range(0, 3), rng.range(0, 3)];
+
+    let mut samples = vec![];
+    while samples.len() < size || !pinned_down(&samples) {
+        let before = random_registers(rng);
+        let [_, a, b, c] = random_registers(rng);
+        let opcode = rng.range(0, 15);
+        let after = numbering[opcode].execute(before, a, b, c).unwrap();
+        samples.push((before, [opcode, a, b, c], after));
+    }
+
+    let mut text = String::new();
+    for (before, instruction, after) in &samples {
+        let _ = writeln!(text, "Before: {:?}", before);
+        let _ = writeln!(text, "{} {} {} {}", instruction[0], instruction[1], instruction[2], instruction[3]);
+        let _ = writeln!(text, "After:  {:?}\n", after);
+    }
+    text.push_str("\n\n");
+
+    let mut registers = [0; 4];
+    let mut program = 0;
+    while program < size.max(1) {
+        let [_, a, b, c] = random_registers(rng);
+        let opcode = rng.range(0, 15);
+        match numbering[opcode].execute(registers, a, b, c) {
+            Some(next) if next[c] < 1_000_000 => registers = next,
+            _ => continue
+        }
+        let _ = writeln!(text, "{} {} {} {}", opcode, a, b, c);
+        program += 1;
+    }
+    text
+}
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        input: "
+Before: [3, 2, 1, 1]
+9 2 1 2
+After:  [3, 2, 2, 1]
+
+
+",
+        part1: Some("1"),
+        part2: None,
+        params: &[]
+    }
+];
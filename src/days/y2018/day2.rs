@@ -0,0 +1,94 @@
+use crate::error::{Error, Result};
+use crate::examples::Example;
+use crate::random::Rng;
+
+solver!(Day2, 2, "Inventory Management System", Vec<String>, generate: size = 250 "box IDs");
+
+fn contains_exactly(s: &str, amount: usize) -> bool {
+    // O(n^2), sad
+    for c in s.chars() {
+        if s.chars().filter(|x| *x == c).count() == amount {
+            return true;
+        }
+    }
+    false
+}
+
+fn differing_characters(a: &str, b: &str) -> usize {
+    // Assumes strings are the same length
+    a.chars()
+        .zip(b.chars())
+        .map(|(ac, bc)| ac != bc)
+        .map(|b| if b { 1 } else { 0 })
+        .sum()
+}
+
+fn equal_chars(a: &str, b: &str) -> String {
+    a.chars().zip(b.chars()).filter(|(ac, bc)| ac == bc).map(|(ac, _)| ac).collect()
+}
+
+pub fn parse(input: &str) -> Result<Vec<String>> {
+    Ok(input.lines().map(|s| s.to_string()).collect())
+}
+
+pub fn part1(ids: &[String]) -> Result<String> {
+    let contains_two = ids.iter().filter(|s| contains_exactly(s, 2)).count();
+    let contains_three = ids.iter().filter(|s| contains_exactly(s, 3)).count();
+
+    Ok((contains_two * contains_three).to_string())
+}
+
+pub fn part2(ids: &[String]) -> Result<String> {
+    // Also O(n^2), sad
+    for a in ids {
+        for b in ids {
+            if differing_characters(a, b) == 1 {
+                // Got it
+                return Ok(equal_chars(a, b));
+            }
+        }
+    }
+    Err(Error::no_solution())
+}
+
+// Exactly one pair of IDs differs by a single letter, random ones practically never do
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let id = |rng: &mut Rng| (0..26).map(|_| (b'a' + rng.range(0, 25) as u8) as char).collect::<Vec<_>>();
+    let mut ids = (0..size.max(2) - 1).map(|_| id(rng)).collect::<Vec<_>>();
+
+    let mut twin = ids[rng.range(0, ids.len() - 1)].clone();
+    let at = rng.range(0, 25);
+    twin[at] = (b'a' + (twin[at] as u8 - b'a' + rng.range(1, 25) as u8) % 26) as char;
+    ids.insert(rng.range(0, ids.len()), twin);
+
+    ids.iter().map(|id| id.iter().collect::<String>() + "\n").collect()
+}
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        input: "
+abcdef
+bababc
+abbcde
+abcccd
+aabcdd
+abcdee
+ababab",
+        part1: Some("12"),
+        part2: None,
+        params: &[]
+    },
+    Example {
+        input: "
+abcde
+fghij
+klmno
+pqrst
+fguij
+axcye
+wvxyz",
+        part1: None,
+        part2: Some("fgij"),
+        params: &[]
+    }
+];
@@ -0,0 +1,146 @@
+use crate::error::{Error, OnLine, Result};
+use crate::examples::Example;
+use crate::geometry::{Bounds, Point, Vector};
+use crate::grid::Grid;
+use crate::parsing;
+use crate::random::Rng;
+
+solver!(Day3, 3, "No Matter How You Slice It", Fabric, generate: size = 1300 "claims");
+
+struct Claim {
+    id: usize,
+    area: Bounds
+}
+
+// The real fabric is 1000 inches square, claims spread over much more than this are a broken input
+const MAX_AREA: usize = 10_000_000;
+
+pub struct Fabric {
+    claims: Vec<Claim>,
+    // How many claims cover each square inch, counting from the top left corner of the claims
+    counts: Grid<usize>,
+    origin: Point
+}
+
+impl Fabric {
+    fn count(&self, p: Point) -> Option<&usize> {
+        self.counts.at(Point::default() + (p - self.origin))
+    }
+}
+
+// Numbers that fit in a u32 can't overflow anything working out the area they cover
+fn parse_claim(line: usize, s: &str) -> Result<Claim> {
+    let (id, x, y, w, h): (usize, u32, u32, u32, u32) = parsing::scan("#{} @ {},{}: {}x{}", s).on_line(line, s)?;
+    Ok(Claim { id, area: area(x as usize, y as usize, w as usize, h as usize) })
+}
+
+// Bounds include their far corner, so an empty claim ends up before it starts
+fn area(x: usize, y: usize, w: usize, h: usize) -> Bounds {
+    let corner = Point::from((x, y));
+    Bounds { min: corner, max: corner + Vector::new(w as isize - 1, h as isize - 1) }
+}
+
+pub fn parse(input: &str) -> Result<Fabric> {
+    let mut claims = vec![];
+    let mut covered: Option<Bounds> = None;
+    for (i, line) in input.lines().enumerate() {
+        let claim = parse_claim(i + 1, line)?;
+        let corners = covered.into_iter().chain(Some(claim.area)).flat_map(|b| [b.min, b.max]);
+        let bounds = Bounds::of(corners).unwrap();
+        if bounds.width().saturating_mul(bounds.height()) > MAX_AREA {
+            return Err(Error::parse(i + 1, line, format!("the claims so far cover {}x{} inches, more than any fabric", bounds.width(), bounds.height())));
+        }
+        covered = Some(bounds);
+        claims.push(claim);
+    }
+
+    let origin = covered.map_or(Point::default(), |bounds| bounds.min);
+    let (width, height) = covered.map_or((0, 0), |bounds| (bounds.width(), bounds.height()));
+    let mut counts = Grid::new(width, height, 0);
+    for claim in &claims {
+        for p in claim.area.points() {
+            let offset = p - origin;
+            counts[(offset.x as usize, offset.y as usize)] += 1;
+        }
+    }
+
+    Ok(Fabric { claims, counts, origin })
+}
+
+pub fn part1(fabric: &Fabric) -> Result<String> {
+    let total = fabric.counts.values().filter(|&&count| count > 1).count();
+    Ok(total.to_string())
+}
+
+pub fn part2(fabric: &Fabric) -> Result<String> {
+    for claim in &fabric.claims {
+        if claim.area.points().all(|p| fabric.count(p) == Some(&1)) {
+            return Ok(claim.id.to_string());
+        }
+    }
+    Err(Error::no_solution())
+}
+
+fn overlaps(a: &Bounds, b: &Bounds) -> bool {
+    a.min.x <= b.max.x && b.min.x <= a.max.x && a.min.y <= b.max.y && b.min.y <= a.max.y
+}
+
+// Claims are scattered and then the ones that came out intact are moved onto others,
+// until only the one claim set aside is left alone
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let side = ((size as f64 * 770.0).sqrt() as usize).max(60);
+    let random_claim = |rng: &mut Rng| {
+        let (w, h) = (rng.range(10, 29), rng.range(10, 29));
+        area(rng.range(0, side - w), rng.range(0, side - h), w, h)
+    };
+
+    let alone = random_claim(rng);
+    let mut claims = vec![];
+    while claims.len() < size.max(2) {
+        let claim = random_claim(rng);
+        if !overlaps(&claim, &alone) { claims.push(claim); }
+    }
+
+    loop {
+        let intact = (0..claims.len()).find(|&i| (0..claims.len()).all(|j| i == j || !overlaps(&claims[i], &claims[j])));
+        let i = match intact {
+            Some(i) => i,
+            None => break
+        };
+        let other = claims[(i + rng.range(1, claims.len() - 1)) % claims.len()];
+        let (w, h) = (claims[i].width(), claims[i].height());
+        let moved = area(
+            rng.range((other.min.x as usize).saturating_sub(w - 1), (other.max.x as usize).min(side - w)),
+            rng.range((other.min.y as usize).saturating_sub(h - 1), (other.max.y as usize).min(side - h)),
+            w, h
+        );
+        if !overlaps(&moved, &alone) { claims[i] = moved; }
+    }
+
+    claims.insert(rng.range(0, claims.len()), alone);
+    claims.iter().enumerate()
+        .map(|(i, c)| format!("#{} @ {},{}: {}x{}\n", i + 1, c.min.x, c.min.y, c.width(), c.height()))
+        .collect()
+}
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        input: "
+#1 @ 1,3: 4x4
+#2 @ 3,1: 4x4
+#3 @ 5,5: 2x2",
+        part1: Some("4"),
+        part2: Some("3"),
+        params: &[]
+    },
+    // Not from the puzzle. Only the part of the fabric the claims cover is counted
+    Example {
+        input: "
+#1 @ 100000,100000: 2x2
+#2 @ 100001,100001: 2x2
+#3 @ 100005,100000: 1x1",
+        part1: Some("1"),
+        part2: Some("3"),
+        params: &[]
+    }
+];
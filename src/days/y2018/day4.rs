@@ -0,0 +1,171 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::error::{Error, OnLine, Result};
+use crate::examples::Example;
+use crate::parsing;
+use crate::random::Rng;
+
+solver!(Day4, 4, "Repose Record", HashMap<usize, Guard>, generate: size = 400 "shifts");
+
+#[derive(Debug)]
+pub struct SleepSpan(isize, isize);
+
+impl SleepSpan {
+    fn contains(&self, minute: isize) -> bool {
+        self.0 <= minute && self.1 > minute
+    }
+    
+    fn length(&self) -> isize {
+        self.1 - self.0
+    }
+}
+
+#[derive(Debug)]
+pub struct Guard(Vec<SleepSpan>);
+
+impl Guard {
+    fn total(&self) -> isize {
+        self.0.iter().map(|x| x.length()).sum()
+    }
+
+    fn spans_containing(&self, minute: usize) -> usize {
+        self.0.iter().filter(|x| x.contains(minute as isize)).count()
+    }
+}
+
+pub fn parse(input: &str) -> Result<HashMap<usize, Guard>> {
+    // Sorting chronologically loses the original line numbers, so keep them around
+    let mut sorted = input.lines().enumerate().map(|(i, l)| (l, i + 1)).collect::<Vec<_>>();
+    sorted.sort_unstable();
+
+    let mut guards: HashMap<usize, Guard> = HashMap::new();
+    let mut current_guard = None;
+    for (line, line_no) in sorted {
+        let (_, hour, minute, rest): (String, usize, usize, String) = parsing::scan("[{} {}:{}] {}", line).on_line(line_no, line)?;
+        let minute = if hour == 23 { (minute as isize) - 60 } else { minute as isize };
+
+        if rest.starts_with("Guard") {
+            let (id,) = parsing::scan("Guard #{} begins shift", &rest).on_line(line_no, line)?;
+            guards.entry(id).or_insert_with(|| Guard(Vec::new()));
+            current_guard = Some(id);
+            continue;
+        }
+
+        let guard = current_guard
+            .and_then(|id| guards.get_mut(&id))
+            .ok_or_else(|| Error::parse(line_no, line, "no guard is on shift"))?;
+
+        if rest == "falls asleep" {
+            guard.0.push(SleepSpan(minute, minute));
+        } else if rest == "wakes up" {
+            guard.0.iter_mut().last()
+                .ok_or_else(|| Error::parse(line_no, line, "guard wakes up without falling asleep"))?
+                .1 = minute;
+        } else {
+            return Err(Error::parse(line_no, line, "unknown event"));
+        }
+    }
+
+    Ok(guards)
+}
+
+pub fn part1(guards: &HashMap<usize, Guard>) -> Result<String> {
+    let sleepiest_guard = guards.iter().max_by_key(|(_, span)| span.total()).ok_or_else(Error::no_solution)?;
+    let sleepiest_minute = (0..60).max_by_key(|minute| sleepiest_guard.1.spans_containing(*minute)).unwrap();
+    Ok((sleepiest_guard.0 * sleepiest_minute).to_string())
+}
+
+pub fn part2(guards: &HashMap<usize, Guard>) -> Result<String> {
+    let mut minutes = vec![(0, 0); 60];
+
+    for (guard_id, guard) in guards {
+        for (minute, best) in minutes.iter_mut().enumerate() {
+            let (sleep_time, _) = *best;
+            if guard.spans_containing(minute) > sleep_time {
+                *best = (guard.spans_containing(minute), *guard_id);
+            }
+        }
+    }
+
+    let (minute, (_, guard)) = minutes.iter().enumerate().max_by_key(|(_, (min, _))| min).unwrap();
+    Ok((minute * guard).to_string())
+}
+
+fn date(day: usize) -> String {
+    const MONTHS: [usize; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+    let (year, mut day) = (1518 + day / 365, day % 365);
+    let mut month = 0;
+    while day >= MONTHS[month] {
+        day -= MONTHS[month];
+        month += 1;
+    }
+    format!("{}-{:02}-{:02}", year, month + 1, day + 1)
+}
+
+// Shifts start a little before or after midnight, and the lines come out of order like the real ones.
+// Every guard naps within their own window of the hour, so the guard who sleeps the most isn't
+// always the one who sleeps most regularly on the same minute
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let guards = (0..size / 15 + 1)
+        .map(|_| {
+            let from = rng.range(0, 40);
+            (rng.range(10, 3500), from, rng.range(from + 10, 59))
+        })
+        .collect::<Vec<_>>();
+    let mut lines = vec![];
+    for shift in 0..size.max(1) {
+        // Starting in March, like the puzzle
+        let day = 59 + shift;
+        let (guard, from, to) = rng.pick(&guards);
+        let start = if rng.chance(70) {
+            lines.push(format!("[{} 23:{:02}] Guard #{} begins shift", date(day - 1), rng.range(45, 59), guard));
+            0
+        } else {
+            let minute = rng.range(0, 5);
+            lines.push(format!("[{} 00:{:02}] Guard #{} begins shift", date(day), minute, guard));
+            minute + 1
+        };
+
+        // The first shift always has a nap, so someone is asleep at some point. A shift that starts late
+        // can leave the guard too few minutes for every nap, but never fewer than 5, so there's still one
+        let first = from.max(start);
+        let window = to - first + 1;
+        let naps = if shift == 0 { rng.range(1, 3) } else { rng.range(0, 3) }.min(window / 2);
+        let mut minutes = BTreeSet::new();
+        while minutes.len() < naps * 2 {
+            minutes.insert(rng.range(first, to));
+        }
+        for (i, minute) in minutes.into_iter().enumerate() {
+            let event = if i % 2 == 0 { "falls asleep" } else { "wakes up" };
+            lines.push(format!("[{} 00:{:02}] {}", date(day), minute, event));
+        }
+    }
+    rng.shuffle(&mut lines);
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        input: "
+[1518-11-01 00:00] Guard #10 begins shift
+[1518-11-01 00:05] falls asleep
+[1518-11-01 00:25] wakes up
+[1518-11-01 00:30] falls asleep
+[1518-11-01 00:55] wakes up
+[1518-11-01 23:58] Guard #99 begins shift
+[1518-11-02 00:40] falls asleep
+[1518-11-02 00:50] wakes up
+[1518-11-03 00:05] Guard #10 begins shift
+[1518-11-03 00:24] falls asleep
+[1518-11-03 00:29] wakes up
+[1518-11-04 00:02] Guard #99 begins shift
+[1518-11-04 00:36] falls asleep
+[1518-11-04 00:46] wakes up
+[1518-11-05 00:03] Guard #99 begins shift
+[1518-11-05 00:45] falls asleep
+[1518-11-05 00:55] wakes up",
+        part1: Some("240"),
+        part2: Some("4455"),
+        params: &[]
+    }
+];
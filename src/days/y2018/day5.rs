@@ -0,0 +1,68 @@
+use crate::error::Result;
+use crate::examples::Example;
+use crate::random::Rng;
+
+solver!(Day5, 5, "Alchemical Reduction", String, generate: size = 50000 "units in the polymer");
+
+fn are_pairs(a: char, b: char) -> bool {
+    if !a.eq_ignore_ascii_case(&b) { return false; }
+    a.is_ascii_uppercase() != b.is_ascii_uppercase()
+}
+
+fn reacted_length(s: &str) -> usize {
+    let mut stack = Vec::with_capacity(s.len());
+    for c in s.chars().filter(|c| !c.is_ascii_whitespace()) {
+        if matches!(stack.last(), Some(&top) if are_pairs(top, c)) {
+            stack.pop();
+        } else {
+            stack.push(c);
+        }
+    }
+    stack.len()
+}
+
+pub fn parse(input: &str) -> Result<String> {
+    Ok(input.trim().to_string())
+}
+
+pub fn part1(polymer: &str) -> Result<String> {
+    Ok(reacted_length(polymer).to_string())
+}
+
+pub fn part2(polymer: &str) -> Result<String> {
+    let shortest = "abcdefghijklmnopqrstuvwxyz".chars()
+        .map(|to_remove| {
+            let s = polymer.chars().filter(|c| c.to_ascii_lowercase() != to_remove).collect::<String>();
+            (to_remove, reacted_length(&s))
+        })
+        .min_by_key(|(_, l)| *l)
+        .unwrap().1;
+    Ok(shortest.to_string())
+}
+
+// Half the time the next unit is the one that reacts with the last, so there's plenty to react
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut polymer = String::new();
+    let mut last: Option<char> = None;
+    for _ in 0..size {
+        let unit = match last {
+            Some(c) if rng.chance(50) => if c.is_ascii_uppercase() { c.to_ascii_lowercase() } else { c.to_ascii_uppercase() },
+            _ => {
+                let c = (b'a' + rng.range(0, 25) as u8) as char;
+                if rng.chance(50) { c.to_ascii_uppercase() } else { c }
+            }
+        };
+        polymer.push(unit);
+        last = Some(unit);
+    }
+    polymer + "\n"
+}
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        input: "dabAcCaCBAcCcaDA",
+        part1: Some("10"),
+        part2: Some("4"),
+        params: &[]
+    }
+];
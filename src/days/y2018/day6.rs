@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+
+use crate::error::{Error, OnLine, Result};
+use crate::examples::Example;
+use crate::geometry::{Bounds, Point};
+use crate::grid::Grid;
+use crate::params::Params;
+use crate::parsing;
+use crate::random::Rng;
+
+solver!(Day6, 6, "Chronal Coordinates", Coordinates, params: [threshold = 10000 "total distance to stay under"],
+    generate: size = 50 "coordinates");
+
+pub struct Coordinates {
+    // Around the points, with a border of 1
+    bounds: Bounds,
+    points: Vec<Point>,
+    // Total distance a part 2 location must stay under
+    threshold: usize
+}
+
+pub fn closest_point(point: Point, points: &[Point]) -> Option<usize> {
+    let mut distances = points.iter().enumerate().map(|(i, p)| (i, point.manhattan(*p))).collect::<Vec<_>>();
+    distances.sort_unstable_by_key(|(_, d)| *d);
+
+    let (shortest_idx, shortest) = distances[0];
+    let (_, next_shortest) = distances[1];
+
+    if shortest == next_shortest {
+        None
+    } else {
+        Some(shortest_idx)
+    }
+}
+
+pub fn parse(input: &str, params: &Params) -> Result<Coordinates> {
+    let points = input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let (x, y) = parsing::scan("{}, {}", line).on_line(i + 1, line)?;
+            Ok(Point::new(x, y))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // closest_point compares the two nearest points
+    if points.len() < 2 { return Err(Error::no_solution()); }
+
+    let bounds = Bounds::of(points.iter().cloned()).unwrap().expand(1);
+    Ok(Coordinates { bounds, points, threshold: params.get("threshold") as usize })
+}
+
+pub fn part1(coordinates: &Coordinates) -> Result<String> {
+    let Coordinates { bounds, ref points, .. } = *coordinates;
+
+    let closest = Grid::from_fn(bounds.width(), bounds.height(), |(x, y)| {
+        closest_point(Point::new(bounds.min.x + x as isize, bounds.min.y + y as isize), points)
+    });
+
+    let total = {
+        let mut areas = HashMap::new();
+        for point in closest.values().flatten() {
+            *areas.entry(point).or_insert(0) += 1;
+        }
+
+        // Areas touching the edge go on forever
+        let (w, h) = closest.size();
+        let edges = closest.row(0).iter()
+            .chain(closest.row(h - 1))
+            .chain(closest.column(0))
+            .chain(closest.column(w - 1));
+        for point in edges.flatten() {
+            areas.remove(point);
+        }
+
+        *areas.values().max().ok_or_else(Error::no_solution)?
+    };
+
+    Ok(total.to_string())
+}
+
+pub fn part2(coordinates: &Coordinates) -> Result<String> {
+    let Coordinates { bounds, ref points, threshold } = *coordinates;
+
+    let size = bounds.points()
+        .filter(|p| points.iter().map(|other| p.manhattan(*other)).sum::<usize>() < threshold)
+        .count();
+    Ok(size.to_string())
+}
+
+// Four points around a fifth fence its area in, so at least one area is finite
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let (x, y, d) = (rng.range(100, 300), rng.range(100, 300), rng.range(5, 40));
+    let mut points = vec![(x, y), (x - d, y), (x + d, y), (x, y - d), (x, y + d)];
+    while points.len() < size.max(5) {
+        let point = (rng.range(40, 360), rng.range(40, 360));
+        if !points.contains(&point) { points.push(point); }
+    }
+    rng.shuffle(&mut points);
+    points.iter().map(|(x, y)| format!("{}, {}\n", x, y)).collect()
+}
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        input: "
+1, 1
+1, 6
+8, 3
+3, 4
+5, 5
+8, 9",
+        part1: Some("17"),
+        part2: Some("16"),
+        params: &[("threshold", 32)]
+    }
+];
@@ -0,0 +1,119 @@
+use std::collections::BTreeSet;
+
+use crate::error::{Error, OnLine, Result};
+use crate::examples::Example;
+use crate::params::Params;
+use crate::parsing;
+use crate::search::{self, Kahn};
+use crate::random::Rng;
+
+solver!(Day7, 7, "The Sum of Its Parts", TaskQueue, params: [
+    workers = 5 "elves working at once",
+    base_time = 60 "seconds every step takes on top of its letter"
+], generate: size = 26 "steps, at most 26");
+
+pub struct TaskQueue {
+    // (prerequisite, step)
+    edges: Vec<(char, char)>,
+    workers: usize,
+    // Seconds every step takes on top of its letter's position in the alphabet
+    base_time: usize
+}
+
+impl TaskQueue {
+    fn new(input: &str, workers: usize, base_time: usize) -> Result<TaskQueue> {
+        let edges = input.lines().enumerate().map(|(i, l)| {
+            let (before, after): (char, char) = parsing::scan("Step {} must be finished before step {} can begin.", l).on_line(i + 1, l)?;
+            // Step durations are derived from the letter, so only A-Z make sense
+            if !before.is_ascii_uppercase() || !after.is_ascii_uppercase() {
+                return Err(Error::parse(i + 1, l, "steps are named A to Z"));
+            }
+            Ok((before, after))
+        }).collect::<Result<Vec<_>>>()?;
+
+        Ok(TaskQueue { edges, workers, base_time })
+    }
+}
+
+pub fn parse(input: &str, params: &Params) -> Result<TaskQueue> {
+    TaskQueue::new(input, params.get("workers") as usize, params.get("base_time") as usize)
+}
+
+pub fn part1(tasks: &TaskQueue) -> Result<String> {
+    // Steps that depend on each other in a cycle can never be done
+    let order = search::topological_sort(tasks.edges.iter().cloned()).ok_or_else(Error::no_solution)?;
+    Ok(order.into_iter().collect())
+}
+
+pub fn time_for_task(task: char, base_time: usize) -> usize {
+    base_time + (task as usize - 'A' as usize + 1)
+}
+
+pub fn part2(tasks: &TaskQueue) -> Result<String> {
+    if tasks.workers == 0 { return Err(Error::no_solution()); }
+    let mut steps = Kahn::new(tasks.edges.iter().cloned());
+
+    // (time it's done, step) for every step someone is working on
+    let mut in_progress: Vec<(usize, char)> = vec![];
+    let mut time = 0;
+    loop {
+        while in_progress.len() < tasks.workers {
+            match steps.pop() {
+                Some(task) => in_progress.push((time + time_for_task(task, tasks.base_time), task)),
+                None => break
+            }
+        }
+
+        // Jump ahead to whenever the next steps are done
+        time = match in_progress.iter().map(|&(done, _)| done).min() {
+            Some(done) => done,
+            None => break
+        };
+        for &(_, task) in in_progress.iter().filter(|&&(done, _)| done == time) {
+            steps.finish(&task);
+        }
+        in_progress.retain(|&(done, _)| done != time);
+    }
+
+    // Steps left over depend on each other in a cycle
+    if !steps.is_done() { return Err(Error::no_solution()); }
+
+    Ok(time.to_string())
+}
+
+// Steps only depend on steps earlier in a shuffled order, so there's never a cycle.
+// Every step depends on at least one earlier one, which also makes sure each step is mentioned
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut steps = (b'A'..b'A' + size.clamp(2, 26) as u8).map(|c| c as char).collect::<Vec<_>>();
+    rng.shuffle(&mut steps);
+
+    let mut edges = BTreeSet::new();
+    for after in 1..steps.len() {
+        edges.insert((rng.range(0, after - 1), after));
+        for before in 0..after {
+            if rng.chance(25) { edges.insert((before, after)); }
+        }
+    }
+
+    let mut lines = edges.into_iter()
+        .map(|(before, after)| format!("Step {} must be finished before step {} can begin.\n", steps[before], steps[after]))
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut lines);
+    lines.concat()
+}
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        input: "
+Step C must be finished before step A can begin.
+Step C must be finished before step F can begin.
+Step A must be finished before step B can begin.
+Step A must be finished before step D can begin.
+Step B must be finished before step E can begin.
+Step D must be finished before step E can begin.
+Step F must be finished before step E can begin.",
+        part1: Some("CABDFE"),
+        part2: Some("15"),
+        params: &[("workers", 2), ("base_time", 0)]
+    }
+];
@@ -0,0 +1,102 @@
+use crate::error::{Error, OnLine, Result};
+use crate::examples::Example;
+use crate::random::Rng;
+
+solver!(Day8, 8, "Memory Maneuver", Node, generate: size = 1500 "nodes");
+
+#[derive(Debug)]
+pub struct Node {
+    child_nodes: Vec<Node>,
+    metadata: Vec<usize>
+}
+
+fn parse_node(i: &mut impl Iterator<Item=usize>) -> Option<Node> {
+    let nodes = i.next()?;
+    let metadatas = i.next()?;
+
+    let child_nodes = (0..nodes).map(|_| parse_node(i)).collect::<Option<_>>()?;
+    let metadata = (0..metadatas).map(|_| i.next()).collect::<Option<_>>()?;
+
+    Some(Node {
+        child_nodes, metadata
+    })
+}
+
+fn sum_metadata(node: &Node) -> usize {
+    node.metadata.iter().sum::<usize>() + node.child_nodes.iter().map(sum_metadata).sum::<usize>()
+}
+
+fn value(node: &Node) -> usize {
+    if node.child_nodes.is_empty() {
+        sum_metadata(node)
+    } else {
+        node.metadata
+            .iter()
+            .flat_map(|idx| idx.checked_sub(1).and_then(|i| node.child_nodes.get(i)))
+            .map(value)
+            .sum()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Node> {
+    let mut numbers = vec![];
+    for (i, line) in input.lines().enumerate() {
+        for c in line.split_whitespace() {
+            numbers.push(c.parse::<usize>().on_line(i + 1, c)?);
+        }
+    }
+
+    let last_line = input.lines().count().max(1);
+    let mut iter = numbers.into_iter();
+    let root = parse_node(&mut iter)
+        .ok_or_else(|| Error::parse(last_line, input.trim(), "input ends in the middle of a node"))?;
+    if iter.next().is_some() {
+        return Err(Error::parse(last_line, input.trim(), "numbers left over after the root node"));
+    }
+    Ok(root)
+}
+
+pub fn part1(root: &Node) -> Result<String> {
+    Ok(sum_metadata(root).to_string())
+}
+
+pub fn part2(root: &Node) -> Result<String> {
+    Ok(value(root).to_string())
+}
+
+// A node header, then its children, then its metadata
+fn write_node(rng: &mut Rng, children: &[Vec<usize>], node: usize, numbers: &mut Vec<usize>) {
+    let count = children[node].len();
+    let entries = rng.range(1, 3);
+    numbers.push(count);
+    numbers.push(entries);
+    for &child in &children[node] {
+        write_node(rng, children, child, numbers);
+    }
+    // Entries point at children counting from 1, now and then at one that isn't there.
+    // A leaf's entries are just numbers to add up
+    for _ in 0..entries {
+        numbers.push(if count == 0 { rng.range(1, 9) } else { rng.range(1, count + 1) });
+    }
+}
+
+// Each node hangs off a random earlier one, which keeps the tree shallow
+fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut children = vec![vec![]; size.max(1)];
+    for node in 1..children.len() {
+        children[rng.range(0, node - 1)].push(node);
+    }
+
+    let mut numbers = vec![];
+    write_node(rng, &children, 0, &mut numbers);
+    numbers.iter().map(|n| n.to_string()).collect::<Vec<_>>().join(" ") + "\n"
+}
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        input: "2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2",
+        part1: Some("138"),
+        part2: Some("66"),
+        params: &[]
+    }
+];
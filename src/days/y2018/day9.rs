@@ -0,0 +1,134 @@
+use std::collections::VecDeque;
+
+use crate::cancel;
+use crate::error::{Error, OnLine, Result};
+use crate::examples::Example;
+use crate::params::Params;
+use crate::parsing;
+use crate::random::Rng;
+
+solver!(Day9, 9, "Marble Mania", Game, params: [marble_factor = 100 "times more marbles in part 2"],
+    generate: size = 70000 "the last marble");
+
+pub struct Game {
+    players: usize,
+    marbles: usize,
+    // How many times more marbles part 2 plays with
+    marble_factor: usize
+}
+
+struct Circle {
+    // oh. Vecs are still faster. who knew?
+    circle: VecDeque<usize>,
+    players: Vec<usize>,
+    current_player: usize,
+    next_marble: usize
+}
+
+impl Circle {
+    fn new(players: usize) -> Circle {
+        Circle {
+            circle: {let mut ll = VecDeque::new(); ll.push_back(0); ll},
+            players: vec![0; players],
+            current_player: 0,
+            next_marble: 1
+        }
+    }
+
+    fn step(&mut self) {
+        if !self.next_marble.is_multiple_of(23) {
+            let f = self.circle.pop_front().unwrap();
+            self.circle.push_back(f);
+
+            self.circle.push_back(self.next_marble);
+        } else {
+            for _ in 0..7 {
+                let b = self.circle.pop_back().unwrap();
+                self.circle.push_front(b);
+            }
+            let removed = self.circle.pop_back().unwrap();
+            
+            let f = self.circle.pop_front().unwrap();
+            self.circle.push_back(f);
+
+            self.players[self.current_player] += removed;
+            self.players[self.current_player] += self.next_marble;
+        }
+
+        self.current_player = (self.current_player + 1) % self.players.len();
+        self.next_marble += 1
+    }
+
+    fn winner_score(&self) -> usize {
+        *self.players.iter().max().unwrap()
+    }
+}
+
+pub fn parse(input: &str, params: &Params) -> Result<Game> {
+    let line = input.trim();
+    let (players, marbles) = parsing::scan("{} players; last marble is worth {} points", line).on_line(1, line)?;
+    if players == 0 { return Err(Error::parse(1, line, "there must be at least one player")); }
+    Ok(Game { players, marbles, marble_factor: params.get("marble_factor") as usize })
+}
+
+pub fn part1(game: &Game) -> Result<String> {
+    let mut circle = Circle::new(game.players);
+    for marble in 0..game.marbles {
+        // Steps are tiny, checking on every one of them shows up in the timings
+        if marble % 4096 == 0 { cancel::check()?; }
+        circle.step();
+    }
+    Ok(circle.winner_score().to_string())
+}
+
+pub fn part2(game: &Game) -> Result<String> {
+    let mut circle = Circle::new(game.players);
+    for marble in 0..game.marbles * game.marble_factor {
+        if marble % 4096 == 0 { cancel::check()?; }
+        circle.step();
+    }
+    Ok(circle.winner_score().to_string())
+}
+
+fn generate(rng: &mut Rng, size: usize) -> String {
+    format!("{} players; last marble is worth {} points\n", rng.range(9, 470), size.max(1))
+}
+
+const EXAMPLES: &[Example] = &[
+    Example {
+        input: "9 players; last marble is worth 25 points",
+        part1: Some("32"),
+        part2: None,
+        params: &[]
+    },
+    Example {
+        input: "10 players; last marble is worth 1618 points",
+        part1: Some("8317"),
+        part2: None,
+        params: &[]
+    },
+    Example {
+        input: "13 players; last marble is worth 7999 points",
+        part1: Some("146373"),
+        part2: None,
+        params: &[]
+    },
+    Example {
+        input: "17 players; last marble is worth 1104 points",
+        part1: Some("2764"),
+        part2: None,
+        params: &[]
+    },
+    Example {
+        input: "21 players; last marble is worth 6111 points",
+        part1: Some("54718"),
+        part2: None,
+        params: &[]
+    },
+    Example {
+        input: "30 players; last marble is worth 5807 points",
+        part1: Some("37305"),
+        part2: None,
+        params: &[]
+    }
+];
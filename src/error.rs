@@ -0,0 +1,118 @@
+use std::fmt;
+use std::io;
+use std::time::Duration;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub struct Error {
+    pub day: Option<usize>,
+    pub kind: ErrorKind
+}
+
+#[derive(Debug)]
+pub enum ErrorKind {
+    // Line numbers start at 1, like in an editor
+    Parse { line: usize, text: String, reason: String },
+    NoSolution,
+    TimedOut(Duration),
+    // Stopped by the runner, see cancel
+    Cancelled,
+    Panicked(String),
+    UnknownParam(String),
+    // The input has to be fetched but that isn't possible
+    Offline,
+    NoSession,
+    // The server answered, but not with a puzzle input
+    Fetch { status: Option<u16>, reason: String },
+    Io(io::Error),
+    Http(reqwest::Error)
+}
+
+impl Error {
+    pub fn parse(line: usize, text: &str, reason: impl ToString) -> Error {
+        ErrorKind::Parse { line, text: text.to_string(), reason: reason.to_string() }.into()
+    }
+
+    pub fn no_solution() -> Error {
+        ErrorKind::NoSolution.into()
+    }
+
+    pub fn fetch(status: Option<u16>, reason: impl ToString) -> Error {
+        ErrorKind::Fetch { status, reason: reason.to_string() }.into()
+    }
+
+    pub fn on_day(self, day: usize) -> Error {
+        Error { day: Some(day), ..self }
+    }
+}
+
+impl From<ErrorKind> for Error {
+    fn from(kind: ErrorKind) -> Error {
+        Error { day: None, kind }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Error {
+        ErrorKind::Io(e).into()
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(e: reqwest::Error) -> Error {
+        ErrorKind::Http(e).into()
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ErrorKind::Parse { line, text, reason } => {
+                // Some inputs are one huge line, don't print all of it
+                let mut shown = text.chars().take(40).collect::<String>();
+                if shown.len() < text.len() { shown.push('…'); }
+                write!(f, "line {}: {} in {:?}", line, reason, shown)
+            },
+            ErrorKind::NoSolution => write!(f, "no solution for this input"),
+            ErrorKind::TimedOut(after) => write!(f, "timed out after {:.1}s", after.as_secs_f64()),
+            ErrorKind::Cancelled => write!(f, "cancelled"),
+            ErrorKind::Panicked(message) => write!(f, "panicked: {}", message),
+            ErrorKind::UnknownParam(name) => write!(f, "unknown parameter {}", name),
+            ErrorKind::Offline => write!(f, "input isn't cached and running offline"),
+            ErrorKind::NoSession => write!(f, "$AOC_SESSION is not set"),
+            ErrorKind::Fetch { status: Some(status), reason } => write!(f, "couldn't fetch input: {} (HTTP {})", reason, status),
+            ErrorKind::Fetch { status: None, reason } => write!(f, "couldn't fetch input: {}", reason),
+            ErrorKind::Io(e) => write!(f, "{}", e),
+            ErrorKind::Http(e) => write!(f, "{}", e)
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.day {
+            Some(day) => write!(f, "day {}: {}", day, self.kind),
+            None => write!(f, "{}", self.kind)
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+// Attaches the position of the line being parsed to a failed conversion or match
+pub trait OnLine<T> {
+    fn on_line(self, line: usize, text: &str) -> Result<T>;
+}
+
+impl<T, E: fmt::Display> OnLine<T> for std::result::Result<T, E> {
+    fn on_line(self, line: usize, text: &str) -> Result<T> {
+        self.map_err(|e| Error::parse(line, text, e))
+    }
+}
+
+impl<T> OnLine<T> for Option<T> {
+    fn on_line(self, line: usize, text: &str) -> Result<T> {
+        self.ok_or_else(|| Error::parse(line, text, "unrecognised input"))
+    }
+}
@@ -0,0 +1,53 @@
+use crate::error::Result;
+use crate::params::Params;
+use crate::solver::AnySolver;
+
+// A worked example from a puzzle statement
+#[derive(Debug)]
+pub struct Example {
+    pub input: &'static str,
+    // None where the statement doesn't give an answer for that part
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+    // Overrides for where the example differs from the real puzzle
+    pub params: &'static [(&'static str, u64)]
+}
+
+impl Example {
+    // Multi-line examples are written starting on the line after the quote
+    pub fn input(&self) -> &'static str {
+        self.input.strip_prefix('\n').unwrap_or(self.input)
+    }
+}
+
+#[derive(Debug)]
+pub struct Check {
+    // Counting from 1, in the order the day lists its examples
+    pub example: usize,
+    pub part: usize,
+    pub expected: &'static str,
+    pub answer: Result<String>
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        matches!(self.answer, Ok(ref answer) if answer == self.expected)
+    }
+}
+
+pub fn check(solver: &dyn AnySolver) -> Vec<Check> {
+    let mut checks = vec![];
+    for (i, example) in solver.examples().iter().enumerate() {
+        for (part, expected) in [(1, example.part1), (2, example.part2)] {
+            let expected = match expected {
+                Some(expected) => expected,
+                None => continue
+            };
+            // Examples are small, so each part might as well get its own parse
+            let answer = solver.parse(example.input(), &Params::from_pairs(example.params))
+                .and_then(|parsed| if part == 1 { solver.part1(parsed.as_ref()) } else { solver.part2(parsed.as_ref()) });
+            checks.push(Check { example: i + 1, part, expected, answer });
+        }
+    }
+    checks
+}
@@ -0,0 +1,133 @@
+use std::thread;
+use std::time::Duration;
+
+use reqwest::StatusCode;
+
+use crate::error::{Error, ErrorKind, Result};
+use crate::submit::{self, Outcome, Submitter};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+// Eric asks automated tools to identify themselves
+pub const USER_AGENT: &str = concat!("aoc18/", env!("CARGO_PKG_VERSION"), " (+https://github.com/xSke/aoc18)");
+
+pub trait Fetcher {
+    fn fetch(&self, year: usize, day: usize) -> Result<String>;
+}
+
+pub struct HttpFetcher {
+    client: reqwest::Client,
+    base_url: String,
+    session: Option<String>,
+    retries: usize,
+    backoff: Duration
+}
+
+impl HttpFetcher {
+    pub fn new(session: Option<String>) -> HttpFetcher {
+        HttpFetcher {
+            client: reqwest::Client::builder()
+                .timeout(Duration::from_secs(30))
+                .build()
+                .expect("couldn't set up the HTTP client"),
+            base_url: BASE_URL.to_string(),
+            session,
+            retries: 3,
+            backoff: Duration::from_secs(1)
+        }
+    }
+
+    pub fn with_base_url(self, base_url: &str) -> HttpFetcher {
+        HttpFetcher { base_url: base_url.trim_end_matches('/').to_string(), ..self }
+    }
+
+    // The wait before retry n is `backoff * 2^n`
+    pub fn with_retries(self, retries: usize, backoff: Duration) -> HttpFetcher {
+        HttpFetcher { retries, backoff, ..self }
+    }
+
+    fn fetch_once(&self, session: &str, year: usize, day: usize) -> Result<String> {
+        let mut response = self.client.get(&format!("{}/{}/day/{}/input", self.base_url, year, day))
+            .header("Cookie", format!("session={}", session))
+            .header("User-Agent", USER_AGENT)
+            .send()?;
+
+        let status = response.status();
+        let body = response.text()?;
+
+        if status == StatusCode::NOT_FOUND {
+            return Err(Error::fetch(Some(status.as_u16()), "puzzle isn't unlocked yet"));
+        }
+        if is_login_page(&body) {
+            return Err(Error::fetch(Some(status.as_u16()), "session is invalid or expired, log in again and update $AOC_SESSION"));
+        }
+        if !status.is_success() {
+            return Err(Error::fetch(Some(status.as_u16()), status.canonical_reason().unwrap_or("unexpected status")));
+        }
+        check_input(&body)?;
+        Ok(body)
+    }
+}
+
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, year: usize, day: usize) -> Result<String> {
+        let session = self.session.as_ref().ok_or_else(|| Error::from(ErrorKind::NoSession))?;
+
+        let mut attempt = 0;
+        loop {
+            match self.fetch_once(session, year, day) {
+                Err(ref e) if attempt < self.retries && is_transient(e) => {
+                    thread::sleep(self.backoff * 2u32.pow(attempt as u32));
+                    attempt += 1;
+                },
+                result => return result
+            }
+        }
+    }
+}
+
+fn is_login_page(body: &str) -> bool {
+    body.contains("Please log in") || body.contains("Puzzle inputs differ by user")
+}
+
+// Whatever gets through here ends up cached, so be picky
+pub fn check_input(body: &str) -> Result<()> {
+    let start = body.trim_start();
+    if start.is_empty() {
+        Err(Error::fetch(None, "response was empty"))
+    } else if start.starts_with('<') {
+        Err(Error::fetch(None, "got an HTML page instead of a puzzle input"))
+    } else if is_login_page(body) {
+        Err(Error::fetch(None, "session is invalid or expired, log in again and update $AOC_SESSION"))
+    } else {
+        Ok(())
+    }
+}
+
+// Network trouble and server errors might go away, everything else won't
+fn is_transient(e: &Error) -> bool {
+    match e.kind {
+        ErrorKind::Http(_) => true,
+        ErrorKind::Fetch { status: Some(status), .. } => status >= 500 || status == 429,
+        _ => false
+    }
+}
+
+impl Submitter for HttpFetcher {
+    // Never retried, a repeated POST could count as a second attempt
+    fn submit(&self, year: usize, day: usize, part: usize, answer: &str) -> Result<Outcome> {
+        let session = self.session.as_ref().ok_or_else(|| Error::from(ErrorKind::NoSession))?;
+
+        let mut response = self.client.post(&format!("{}/{}/day/{}/answer", self.base_url, year, day))
+            .header("Cookie", format!("session={}", session))
+            .header("User-Agent", USER_AGENT)
+            .form(&[("level", part.to_string()), ("answer", answer.to_string())])
+            .send()?;
+
+        let status = response.status();
+        let body = response.text()?;
+        if !status.is_success() {
+            return Err(Error::fetch(Some(status.as_u16()), status.canonical_reason().unwrap_or("unexpected status")));
+        }
+        submit::parse_response(&body)
+    }
+}
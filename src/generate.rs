@@ -0,0 +1,19 @@
+use crate::random::Rng;
+
+// Makes up inputs shaped like the real ones, for stress tests and benchmarks. Days declare theirs
+// in solver!, what size means is up to each day, and the default is about how big the real inputs are
+pub struct Generator {
+    pub size: &'static str,
+    pub default_size: usize,
+    generate: fn(&mut Rng, usize) -> String
+}
+
+impl Generator {
+    pub const fn new(size: &'static str, default_size: usize, generate: fn(&mut Rng, usize) -> String) -> Generator {
+        Generator { size, default_size, generate }
+    }
+
+    pub fn generate(&self, size: usize, seed: u64) -> String {
+        (self.generate)(&mut Rng::new(seed), size)
+    }
+}
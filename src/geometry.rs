@@ -0,0 +1,314 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+// Screen coordinates like the puzzles use: x grows to the right and y grows downwards
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Vector {
+    pub x: isize,
+    pub y: isize
+}
+
+impl Point {
+    pub fn new(x: isize, y: isize) -> Point {
+        Point { x, y }
+    }
+
+    pub fn manhattan(self, other: Point) -> usize {
+        let d = other - self;
+        (d.x.abs() + d.y.abs()) as usize
+    }
+
+    pub fn chebyshev(self, other: Point) -> usize {
+        let d = other - self;
+        d.x.abs().max(d.y.abs()) as usize
+    }
+
+    pub fn step(self, direction: Direction) -> Point {
+        self + direction.vector()
+    }
+
+    // In reading order, like Grid::neighbors4
+    pub fn neighbors4(self) -> impl Iterator<Item=Point> {
+        Direction::READING_ORDER.iter().map(move |&d| self.step(d))
+    }
+
+    // For indexing into a Grid, None when either coordinate is negative
+    pub fn to_unsigned(self) -> Option<(usize, usize)> {
+        if self.x < 0 || self.y < 0 { None } else { Some((self.x as usize, self.y as usize)) }
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Point {
+        Point::new(x as isize, y as isize)
+    }
+}
+
+// The way answers want it
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+// Reading order: top to bottom, then left to right
+impl Ord for Point {
+    fn cmp(&self, other: &Point) -> Ordering {
+        (self.y, self.x).cmp(&(other.y, other.x))
+    }
+}
+
+impl PartialOrd for Point {
+    fn partial_cmp(&self, other: &Point) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, v: Vector) -> Point {
+        Point::new(self.x + v.x, self.y + v.y)
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, v: Vector) {
+        *self = *self + v;
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Point) -> Vector {
+        Vector::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Vector {
+    pub fn new(x: isize, y: isize) -> Vector {
+        Vector { x, y }
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, v: Vector) -> Vector {
+        Vector::new(self.x + v.x, self.y + v.y)
+    }
+}
+
+impl Mul<isize> for Vector {
+    type Output = Vector;
+
+    fn mul(self, n: isize) -> Vector {
+        Vector::new(self.x * n, self.y * n)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.x, -self.y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Point3 {
+    pub x: isize,
+    pub y: isize,
+    pub z: isize
+}
+
+impl Point3 {
+    pub fn new(x: isize, y: isize, z: isize) -> Point3 {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(self, other: Point3) -> usize {
+        ((self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()) as usize
+    }
+
+    pub fn chebyshev(self, other: Point3) -> usize {
+        (self.x - other.x).abs().max((self.y - other.y).abs()).max((self.z - other.z).abs()) as usize
+    }
+}
+
+impl Add for Point3 {
+    type Output = Point3;
+
+    fn add(self, other: Point3) -> Point3 {
+        Point3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Point3;
+
+    fn sub(self, other: Point3) -> Point3 {
+        Point3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right
+}
+
+impl Direction {
+    pub const READING_ORDER: [Direction; 4] = [Direction::Up, Direction::Left, Direction::Right, Direction::Down];
+
+    // The arrows carts and the like are drawn with
+    pub fn from_arrow(c: char) -> Option<Direction> {
+        match c {
+            '^' => Some(Direction::Up),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            '>' => Some(Direction::Right),
+            _ => None
+        }
+    }
+
+    pub fn vector(self) -> Vector {
+        match self {
+            Direction::Up => Vector::new(0, -1),
+            Direction::Down => Vector::new(0, 1),
+            Direction::Left => Vector::new(-1, 0),
+            Direction::Right => Vector::new(1, 0)
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        self.turn_left().reverse()
+    }
+
+    pub fn reverse(self) -> Direction {
+        self.turn_left().turn_left()
+    }
+}
+
+// Inclusive on both corners
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    pub min: Point,
+    pub max: Point
+}
+
+impl Bounds {
+    // None if there aren't any points
+    pub fn of(points: impl IntoIterator<Item=Point>) -> Option<Bounds> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Bounds { min: first, max: first }, |b, p| Bounds {
+            min: Point::new(b.min.x.min(p.x), b.min.y.min(p.y)),
+            max: Point::new(b.max.x.max(p.x), b.max.y.max(p.y))
+        }))
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max.x - self.min.x + 1) as usize
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max.y - self.min.y + 1) as usize
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        (self.min.x..=self.max.x).contains(&p.x) && (self.min.y..=self.max.y).contains(&p.y)
+    }
+
+    pub fn on_edge(&self, p: Point) -> bool {
+        self.contains(p) && (p.x == self.min.x || p.x == self.max.x || p.y == self.min.y || p.y == self.max.y)
+    }
+
+    // Grows every side by n
+    pub fn expand(&self, n: isize) -> Bounds {
+        Bounds { min: self.min + Vector::new(-n, -n), max: self.max + Vector::new(n, n) }
+    }
+
+    // Everything inside, in reading order
+    pub fn points(&self) -> impl Iterator<Item=Point> {
+        let Bounds { min, max } = *self;
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point::new(x, y)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds_cover_every_point() {
+        assert_eq!(Bounds::of(vec![]), None);
+
+        let bounds = Bounds::of(vec![Point::new(2, -1), Point::new(-3, 4), Point::new(0, 0)]).unwrap();
+        assert_eq!(bounds, Bounds { min: Point::new(-3, -1), max: Point::new(2, 4) });
+        assert_eq!((bounds.width(), bounds.height()), (6, 6));
+        assert!(bounds.contains(Point::new(2, 4)));
+        assert!(!bounds.contains(Point::new(3, 4)));
+        assert!(bounds.on_edge(Point::new(-3, 1)));
+        assert!(!bounds.on_edge(Point::new(-2, 1)));
+        assert!(!bounds.on_edge(Point::new(-4, 1)));
+
+        let single = Bounds::of(vec![Point::new(5, 5)]).unwrap();
+        assert_eq!((single.width(), single.height()), (1, 1));
+        assert_eq!(single.expand(1), Bounds { min: Point::new(4, 4), max: Point::new(6, 6) });
+    }
+
+    #[test]
+    fn bounds_go_in_reading_order() {
+        let bounds = Bounds { min: Point::new(0, 0), max: Point::new(1, 1) };
+        let points = bounds.points().collect::<Vec<_>>();
+        assert_eq!(points, [Point::new(0, 0), Point::new(1, 0), Point::new(0, 1), Point::new(1, 1)]);
+        assert!(points.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn turns_go_round() {
+        let mut facing = Direction::Up;
+        let mut lefts = vec![];
+        for _ in 0..4 {
+            facing = facing.turn_left();
+            lefts.push(facing);
+        }
+        assert_eq!(lefts, [Direction::Left, Direction::Down, Direction::Right, Direction::Up]);
+
+        for &d in &Direction::READING_ORDER {
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(d.turn_right(), d.turn_left().reverse());
+            assert_eq!(d.reverse().vector(), -d.vector());
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        // y grows downwards, so turning right from up heads towards bigger x
+        assert_eq!(Point::new(0, 0).step(Direction::Up.turn_right()), Point::new(1, 0));
+    }
+
+    #[test]
+    fn neighbors_are_in_reading_order() {
+        let neighbors = Point::new(3, 3).neighbors4().collect::<Vec<_>>();
+        assert_eq!(neighbors, [Point::new(3, 2), Point::new(2, 3), Point::new(4, 3), Point::new(3, 4)]);
+        assert!(neighbors.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+}
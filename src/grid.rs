@@ -0,0 +1,260 @@
+use std::ops::{Index, IndexMut};
+
+use crate::error::{Error, Result};
+use crate::geometry::Point;
+
+// Positions are (x, y) with y growing downwards, and everything iterates in reading order
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> where T: Clone {
+        Grid { width, height, cells: vec![fill; width * height] }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut((usize, usize)) -> T) -> Grid<T> {
+        let cells = (0..height).flat_map(|y| (0..width).map(move |x| (x, y))).map(&mut f).collect();
+        Grid { width, height, cells }
+    }
+
+    // One row per line, with `cell` turning each character into a cell or rejecting it.
+    // Lines shorter than the longest one are padded with spaces, which go through `cell` too
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Grid<T>> {
+        let width = input.lines().map(|line| line.chars().count()).max().unwrap_or(0);
+        let mut cells = Vec::new();
+        let mut height = 0;
+        for (y, line) in input.lines().enumerate() {
+            let padding = std::iter::repeat_n(' ', width - line.chars().count());
+            for c in line.chars().chain(padding) {
+                cells.push(cell(c).ok_or_else(|| Error::parse(y + 1, line, format!("unexpected {:?}", c)))?);
+            }
+            height += 1;
+        }
+        Ok(Grid { width, height, cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    pub fn contains(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: (usize, usize)) -> Option<&T> {
+        if self.contains(pos) { Some(&self.cells[pos.1 * self.width + pos.0]) } else { None }
+    }
+
+    pub fn get_mut(&mut self, pos: (usize, usize)) -> Option<&mut T> {
+        if self.contains(pos) { Some(&mut self.cells[pos.1 * self.width + pos.0]) } else { None }
+    }
+
+    // Like get, for points that might be off the top or left edge too
+    pub fn at(&self, p: Point) -> Option<&T> {
+        p.to_unsigned().and_then(|pos| self.get(pos))
+    }
+
+    // Points that fall off one edge come back in on the opposite one
+    pub fn get_wrapping(&self, p: Point) -> &T {
+        assert!(!self.cells.is_empty(), "wrapping around an empty grid");
+        let x = p.x.rem_euclid(self.width as isize) as usize;
+        let y = p.y.rem_euclid(self.height as isize) as usize;
+        &self.cells[y * self.width + x]
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item=(usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item=((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item=&T> {
+        self.cells.iter()
+    }
+
+    // Up, left, right, down: reading order again, but only the ones inside the grid
+    pub fn neighbors4(&self, (x, y): (usize, usize)) -> impl Iterator<Item=(usize, usize)> {
+        let offsets = &[(0, -1), (-1, 0), (1, 0), (0, 1)];
+        self.offsets(x, y, offsets)
+    }
+
+    // Diagonals too
+    pub fn neighbors8(&self, (x, y): (usize, usize)) -> impl Iterator<Item=(usize, usize)> {
+        let offsets = &[(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+        self.offsets(x, y, offsets)
+    }
+
+    fn offsets(&self, x: usize, y: usize, offsets: &'static [(isize, isize)]) -> impl Iterator<Item=(usize, usize)> {
+        let (width, height) = self.size();
+        offsets.iter()
+            .map(move |&(dx, dy)| (x.wrapping_add(dx as usize), y.wrapping_add(dy as usize)))
+            .filter(move |&(x, y)| x < width && y < height)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item=&[T]> {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item=&T> {
+        assert!(x < self.width, "column {} is outside a grid {} wide", x, self.width);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item=impl Iterator<Item=&T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    // The w by h rectangle with its top left corner at (x, y), which has to fit inside the grid
+    pub fn view(&self, (x, y): (usize, usize), (width, height): (usize, usize)) -> View<'_, T> {
+        assert!(x + width <= self.width && y + height <= self.height, "view doesn't fit in the grid");
+        View { grid: self, origin: (x, y), width, height }
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(&mut f).collect() }
+    }
+
+    // One line per row, without a trailing newline
+    pub fn render(&self, mut f: impl FnMut(&T) -> char) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&mut f).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: (usize, usize)) -> &T {
+        let (width, height) = self.size();
+        self.get(pos).unwrap_or_else(|| panic!("{:?} is outside a {}x{} grid", pos, width, height))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut T {
+        let (width, height) = self.size();
+        self.get_mut(pos).unwrap_or_else(|| panic!("{:?} is outside a {}x{} grid", pos, width, height))
+    }
+}
+
+// A rectangle borrowed out of a Grid, with positions relative to its own top left corner
+pub struct View<'a, T> {
+    grid: &'a Grid<T>,
+    origin: (usize, usize),
+    width: usize,
+    height: usize
+}
+
+impl<'a, T> View<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): (usize, usize)) -> Option<&'a T> {
+        if x < self.width && y < self.height { self.grid.get((self.origin.0 + x, self.origin.1 + y)) } else { None }
+    }
+
+    pub fn row(&self, y: usize) -> &'a [T] {
+        assert!(y < self.height, "row {} is outside a view {} high", y, self.height);
+        let (x0, y0) = self.origin;
+        &self.grid.row(y0 + y)[x0..x0 + self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item=&'a [T]> + '_ {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    pub fn values(&self) -> impl Iterator<Item=&'a T> + '_ {
+        self.rows().flatten()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Each cell knows where it is, 10 * y + x
+    fn numbered(width: usize, height: usize) -> Grid<usize> {
+        Grid::from_fn(width, height, |(x, y)| 10 * y + x)
+    }
+
+    #[test]
+    fn cells_are_stored_row_by_row() {
+        let grid = numbered(3, 2);
+        assert_eq!(grid[(2, 1)], 12);
+        assert_eq!(grid.values().cloned().collect::<Vec<_>>(), [0, 1, 2, 10, 11, 12]);
+        assert_eq!(grid.row(1), [10, 11, 12]);
+        assert_eq!(grid.column(1).cloned().collect::<Vec<_>>(), [1, 11]);
+        assert_eq!(grid.positions().nth(4), Some((1, 1)));
+    }
+
+    #[test]
+    fn nothing_outside_the_grid() {
+        let grid = numbered(3, 2);
+        assert_eq!(grid.get((2, 1)), Some(&12));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+        assert_eq!(grid.at(Point::new(-1, 0)), None);
+        assert_eq!(grid.at(Point::new(0, 1)), Some(&10));
+        assert_eq!(*grid.get_wrapping(Point::new(-1, 2)), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "(3, 0) is outside a 3x2 grid")]
+    fn indexing_outside_panics() {
+        let _ = numbered(3, 2)[(3, 0)];
+    }
+
+    #[test]
+    fn neighbors_stop_at_the_edges() {
+        let grid = numbered(3, 3);
+        assert_eq!(grid.neighbors4((0, 0)).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbors4((2, 2)).collect::<Vec<_>>(), [(2, 1), (1, 2)]);
+        assert_eq!(grid.neighbors4((1, 1)).collect::<Vec<_>>(), [(1, 0), (0, 1), (2, 1), (1, 2)]);
+        assert_eq!(grid.neighbors8((0, 0)).collect::<Vec<_>>(), [(1, 0), (0, 1), (1, 1)]);
+        assert_eq!(grid.neighbors8((2, 1)).collect::<Vec<_>>(), [(1, 0), (2, 0), (1, 1), (1, 2), (2, 2)]);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn parse_pads_short_lines() {
+        let grid = Grid::parse("#.\n#", Some).unwrap();
+        assert_eq!(grid.size(), (2, 2));
+        assert_eq!(grid.render(|&c| c), "#.\n# ");
+        assert!(Grid::parse("#x", |c| if c == '#' { Some(c) } else { None }).is_err());
+    }
+
+    #[test]
+    fn views_are_relative_to_their_corner() {
+        let grid = numbered(4, 3);
+        let view = grid.view((1, 1), (2, 2));
+        assert_eq!(view.get((0, 0)), Some(&11));
+        assert_eq!(view.get((2, 0)), None);
+        assert_eq!(view.values().cloned().collect::<Vec<_>>(), [11, 12, 21, 22]);
+    }
+}
@@ -0,0 +1,159 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use indicatif::ProgressBar;
+
+use crate::error::{Error, ErrorKind, Result};
+use crate::fetch::Fetcher;
+use crate::params::Params;
+use crate::registry::LEGACY_YEAR;
+use crate::solver::AnySolver;
+
+pub const DEFAULT_DIR: &str = "inputs";
+
+// Puzzle inputs as `<dir>/<year>/<day>.txt`, fetched on first use unless offline
+pub struct InputCache {
+    root: PathBuf,
+    dir: PathBuf,
+    year: usize,
+    offline: bool,
+    fetcher: Box<dyn Fetcher + Send + Sync>
+}
+
+pub struct CachedInput {
+    pub day: usize,
+    pub path: PathBuf,
+    pub size: u64
+}
+
+impl InputCache {
+    pub fn new(dir: impl Into<PathBuf>, year: usize, offline: bool, fetcher: Box<dyn Fetcher + Send + Sync>) -> InputCache {
+        let root = dir.into();
+        InputCache { dir: root.join(year.to_string()), root, year, offline, fetcher }
+    }
+
+    pub fn year(&self) -> usize {
+        self.year
+    }
+
+    // Where every year's directory goes
+    pub fn root(&self) -> &PathBuf {
+        &self.root
+    }
+
+    // The year's own directory
+    pub fn dir(&self) -> &PathBuf {
+        &self.dir
+    }
+
+    pub fn path(&self, day: usize) -> PathBuf {
+        self.dir.join(format!("{}.txt", day))
+    }
+
+    pub fn is_cached(&self, day: usize) -> bool {
+        self.path(day).exists()
+    }
+
+    pub fn cached(&self, day: usize) -> Result<Option<String>> {
+        match fs::read_to_string(self.path(day)) {
+            Ok(input) => Ok(Some(input)),
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into())
+        }
+    }
+
+    pub fn get(&self, day: usize, pb: &ProgressBar) -> Result<String> {
+        if let Some(input) = self.cached(day)? {
+            return Ok(input);
+        }
+
+        if self.offline {
+            return Err(Error::from(ErrorKind::Offline).on_day(day));
+        }
+
+        pb.set_message("fetching puzzle input...");
+        let input = self.fetcher.fetch(self.year, day).map_err(|e| e.on_day(day))?;
+
+        fs::create_dir_all(&self.dir)?;
+        fs::write(self.path(day), &input)?;
+        Ok(input)
+    }
+
+    pub fn list(&self) -> Result<Vec<CachedInput>> {
+        let mut inputs = day_files(&self.dir)?;
+        inputs.sort_unstable_by_key(|i| i.day);
+        Ok(inputs)
+    }
+
+    // A cached input is fine if the solver can parse it, which also catches saved error pages
+    pub fn verify(&self, solver: &dyn AnySolver) -> Result<()> {
+        let input = self.cached(solver.day())?
+            .ok_or_else(|| Error::from(io::Error::new(io::ErrorKind::NotFound, "not cached")).on_day(solver.day()))?;
+        if input.trim().is_empty() {
+            return Err(Error::parse(1, "", "input is empty").on_day(solver.day()));
+        }
+        solver.parse(&input, &Params::new()).map(|_| ())
+    }
+
+    // Returns whether there was anything to remove
+    pub fn clear(&self, day: usize) -> Result<bool> {
+        match fs::remove_file(self.path(day)) {
+            Ok(()) => Ok(true),
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => Ok(false),
+            Err(e) => Err(e.into())
+        }
+    }
+
+    // Inputs cached before years were tracked sit right in the cache directory. Only LEGACY_YEAR
+    // has any, and they're left alone until adopt_legacy is asked to move them
+    pub fn legacy(&self) -> Result<Vec<CachedInput>> {
+        if self.year == LEGACY_YEAR { day_files(&self.root) } else { Ok(vec![]) }
+    }
+
+    // Moves the legacy inputs into the year's directory, returning the ones that moved. One the
+    // year already has a copy of stays where it is
+    pub fn adopt_legacy(&self) -> Result<Vec<CachedInput>> {
+        let mut adopted = vec![];
+        for input in self.legacy()? {
+            let path = self.path(input.day);
+            if path.exists() { continue; }
+            fs::create_dir_all(&self.dir)?;
+            fs::rename(&input.path, &path)?;
+            adopted.push(CachedInput { path, ..input });
+        }
+        Ok(adopted)
+    }
+
+    pub fn clear_all(&self) -> Result<usize> {
+        let inputs = self.list()?;
+        for input in &inputs {
+            fs::remove_file(&input.path)?;
+        }
+        Ok(inputs.len())
+    }
+}
+
+// The `<day>.txt` files in a directory, anything else in there isn't ours
+fn day_files(dir: &Path) -> Result<Vec<CachedInput>> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(ref e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e.into())
+    };
+
+    let mut inputs = vec![];
+    for entry in entries {
+        let entry = entry?;
+        let path = entry.path();
+        let day = path.file_name()
+            .and_then(|n| n.to_str())
+            .and_then(|n| n.strip_suffix(".txt"))
+            .and_then(|n| n.parse().ok());
+        if let Some(day) = day {
+            inputs.push(CachedInput { day, path, size: entry.metadata()?.len() });
+        }
+    }
+    Ok(inputs)
+}
+
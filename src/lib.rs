@@ -0,0 +1,25 @@
+#[macro_use]
+pub mod solver;
+pub mod error;
+pub mod cancel;
+pub mod params;
+pub mod config;
+pub mod examples;
+pub mod parsing;
+pub mod random;
+pub mod geometry;
+pub mod grid;
+pub mod search;
+pub mod cycle;
+pub mod answers;
+pub mod fetch;
+pub mod inputs;
+pub mod bench;
+pub mod days;
+pub mod generate;
+pub mod registry;
+pub mod runner;
+pub mod submit;
+pub mod output;
+pub mod scaffold;
+pub mod watch;
@@ -1,94 +1,774 @@
-mod day1;
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-
-use std::fs::{self, File};
-use std::io::{Read, Write};
-use std::path::Path;
-
-fn run<T>(aoc_session: &str, day_num: usize, name: &'static str, part_1_fn: impl Fn(&str) -> (String, T), part_2_fn: impl Fn(&str, T) -> String) {
-    let input_file_path = format!("inputs/{}.txt", day_num);
-
-    let pb = indicatif::ProgressBar::new_spinner();
-    pb.set_style(indicatif::ProgressStyle::default_spinner().template("{elapsed:>3} {spinner} {prefix:<35!.cyan} {msg}"));
-    pb.set_prefix(&format!("Day {}: {}", day_num, name));
-    pb.enable_steady_tick(100);
-
-    let input = if Path::new(&input_file_path).exists() {
-        let mut input = String::new();
-        File::open(input_file_path).unwrap().read_to_string(&mut input).unwrap();
-        input
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use aoc18::answers::{self, Answers};
+use aoc18::bench::{self, Baseline, Elapsed};
+use aoc18::config::{self, Config};
+use aoc18::examples;
+use aoc18::fetch::HttpFetcher;
+use aoc18::inputs::{self, InputCache};
+use aoc18::output::{self, Format};
+use aoc18::params::Params;
+use aoc18::registry::{self, Registry};
+use aoc18::runner;
+use aoc18::scaffold;
+use aoc18::solver::AnySolver;
+use aoc18::submit::{self, History, Outcome, Submitter};
+use aoc18::watch::{self, Watcher};
+
+const USAGE: &str = "\
+Usage: aoc18 [command] [days] [options]
+
+Commands:
+    run [days]                    Solve days and check the answers against answers.txt (default)
+    record [days]                 Solve days and save the answers to answers.txt
+    bench [days]                  Time parsing and both parts of each day
+    check-examples [days]         Solve the examples from the puzzle statements
+    submit <day> <1|2>            Post an answer to adventofcode.com
+    inputs <action> [days]        Manage cached puzzle inputs: list, verify, clear or adopt
+    new <day> <title>             Start a new day in src/days/y<year>, it's registered on the next build
+    watch <day>                   Solve a day again whenever its input, --input file or source changes
+    gen <day>                     Print a random input for a day, for stress tests and benchmarks
+
+run and record exit with status 1 if a day fails or an answer doesn't match answers.txt.
+new and watch work on the checkout in or above the current directory.
+Inputs cached before years were tracked stay in the cache directory until inputs adopt moves them
+into 2018's.
+
+Days are lists and ranges like 1-5,9,15, all days are run if none are given. They're the days of
+the latest year that has any, unless --year picks another.
+
+Parameters are numbers the puzzles fix, like day 7's 5 workers taking 60 seconds plus the
+letter per step. Answers are only checked and recorded when a day runs with its own.
+";
+
+// The rest of the usage, after the params of the year the days come from
+const OPTIONS: &str = "
+Options:
+    --all                         Run every day
+    --part 1|2                    Only run one part
+    --input <file>                Use this instead of the cached input, - for stdin (one day only, not for watch)
+    --param day<n>.<name>=<value> Change one of a day's parameters, like day7.workers=2 (repeatable)
+    --format pretty|json|csv      How to print results (run and record)
+    -j, --jobs <n>                Run n days at once, 0 for one per core (run and record)
+    --timeout <seconds>           Give up on a day after this long, 0 to wait forever (default 60)
+    --runs <n>, --warmup <n>      Timed and untimed runs per phase (bench)
+    --threshold <percent>         Change against the baseline to flag (bench)
+    --save                        Save the timings as the new baseline (bench)
+    --answer <answer>             Submit this instead of solving (submit)
+    --size <n>, --seed <n>        How big to make the input and what to make it from (gen)
+    --year <year>                 Which year's days to use, also $AOC_YEAR
+    --offline                     Never fetch inputs, also $AOC_OFFLINE
+    --cache-dir <dir>             Where inputs are cached, also $AOC_CACHE_DIR
+    --config <file>               Defaults for year, jobs, timeout, format and params (default aoc18.conf), also $AOC_CONFIG
+    --color auto|always|never     Colour the output, auto leaves it off when stdout isn't a terminal
+    --no-progress                 Print a line per day instead of spinners, the default when stdout isn't a terminal
+";
+
+// Built from the registry, so it's always the params the days really have
+fn print_usage(registry: &Registry) {
+    print!("{}", USAGE);
+    let params = registry.iter()
+        .flat_map(|solver| solver.params().iter().map(move |param| (solver.day(), param)))
+        .collect::<Vec<_>>();
+    if params.is_empty() {
+        println!("{} doesn't have any.", registry.year());
     } else {
-        pb.set_message("fetching puzzle input...");
+        println!("{} has:", registry.year());
+    }
+    for (day, param) in params {
+        println!("    {:<20} {} ({})", format!("day{}.{}", day, param.name), param.description, param.default);
+    }
+    println!("In the config they can be tied to a year, like 2018.day7.workers, otherwise they're for any year.");
+    print!("{}", OPTIONS);
+}
 
-        let client = reqwest::Client::new();
-        let mut response = client.get(&format!("https://adventofcode.com/2018/day/{}/input", day_num))
-            .header("Cookie", format!("session={}", aoc_session))
-            .send().unwrap();
+fn usage_error(message: impl std::fmt::Display) -> ! {
+    eprintln!("{}", message);
+    eprintln!("Run with --help for usage.");
+    std::process::exit(2);
+}
+
+fn value<'a>(flag: &str, args: &mut impl Iterator<Item = &'a String>) -> &'a String {
+    args.next().unwrap_or_else(|| usage_error(format!("{} needs a value", flag)))
+}
 
-        let input = response.text().unwrap();
-        fs::create_dir_all("inputs/").unwrap();
-        File::create(input_file_path).unwrap().write_all(input.as_bytes()).unwrap();
-        input
+fn number<T: std::str::FromStr>(flag: &str, args: &mut std::slice::Iter<String>) -> T {
+    let value = value(flag, args);
+    value.parse().unwrap_or_else(|_| usage_error(format!("{} takes a number, not {:?}", flag, value)))
+}
+
+// Which days and parts to solve, shared by the commands that solve days
+#[derive(Default)]
+struct Selection {
+    days: Vec<String>,
+    all: bool,
+    part: Option<usize>,
+    input: Option<String>,
+    params: Vec<String>
+}
+
+impl Selection {
+    // Returns false for arguments that are up to the command
+    fn take(&mut self, arg: &str, args: &mut std::slice::Iter<String>) -> bool {
+        match arg {
+            "--all" => self.all = true,
+            "--part" => self.part = match number("--part", args) {
+                part @ 1..=2 => Some(part),
+                part => usage_error(format!("there is no part {}", part))
+            },
+            "--input" => self.input = Some(value("--input", args).clone()),
+            "--param" => self.params.push(value("--param", args).clone()),
+            _ if !arg.starts_with('-') => self.days.push(arg.to_string()),
+            _ => return false
+        }
+        true
+    }
+
+    fn solvers(&self, registry: &Registry) -> Vec<&'static dyn AnySolver> {
+        if self.all && !self.days.is_empty() {
+            usage_error("--all can't be combined with a list of days");
+        }
+        if registry.is_empty() {
+            usage_error(format!("no days of {} are solved yet", registry.year()));
+        }
+        let solvers = if self.days.is_empty() {
+            registry.iter().collect()
+        } else {
+            registry.select(&self.days.join(",")).unwrap_or_else(|e| usage_error(e))
+        };
+        if self.input.is_some() && solvers.len() != 1 {
+            usage_error("--input needs exactly one day");
+        }
+        solvers
+    }
+
+    // The params from the command line go on top of the ones in the config
+    fn options(&self, config: &Config, year: usize) -> runner::RunOptions {
+        let input = self.input.as_ref().map(|path| {
+            let read = if path == "-" {
+                let mut input = String::new();
+                std::io::stdin().read_to_string(&mut input).map(|_| input)
+            } else {
+                std::fs::read_to_string(path)
+            };
+            read.unwrap_or_else(|e| usage_error(format!("Couldn't read {}: {}", path, e)))
+        });
+        let parts = self.part.map_or(vec![1, 2], |part| vec![part]);
+        let mut config = config.clone();
+        for param in &self.params {
+            config.set_param(param).unwrap_or_else(|e| usage_error(e));
+        }
+        runner::RunOptions { parts, input, params: config.all_params(year), ..Default::default() }
+    }
+}
+
+fn jobs(n: usize) -> usize {
+    match n {
+        0 => std::thread::available_parallelism().map_or(1, |n| n.get()),
+        n => n
+    }
+}
+
+// From --timeout or the config, where infinite, negative or absurdly long ones are mistakes
+fn timeout(secs: f64) -> Option<std::time::Duration> {
+    if secs == 0.0 { return None; }
+    match std::time::Duration::try_from_secs_f64(secs) {
+        Ok(timeout) => Some(timeout),
+        Err(_) => usage_error(format!("a timeout of {} seconds can't be waited for, use 0 to wait forever", secs))
+    }
+}
+
+// `record` stores whatever the run produces as the new expected answers.
+// Without `progress` the pretty format prints each day once it's done instead of drawing spinners
+// False if any day failed or got a wrong answer
+fn run_days(inputs: &InputCache, registry: &Registry, config: &Config, args: &[String], record: bool, progress: bool) -> bool {
+    let mut selection = Selection::default();
+    let mut format = config.format.unwrap_or(Format::Pretty);
+    let mut jobs = config.jobs.map_or(1, self::jobs);
+    let mut timeout = config.timeout.map_or(runner::RunOptions::default().timeout, self::timeout);
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if selection.take(arg, &mut args) { continue; }
+        match arg.as_str() {
+            "--format" => format = value("--format", &mut args).parse().unwrap_or_else(|e| usage_error(e)),
+            "-j" | "--jobs" => jobs = self::jobs(number("--jobs", &mut args)),
+            "--timeout" => timeout = self::timeout(number("--timeout", &mut args)),
+            _ => usage_error(format!("unknown option {}", arg))
+        }
+    }
+    if record && selection.input.is_some() {
+        usage_error("only answers for the real inputs can be recorded");
+    }
+
+    let solvers = selection.solvers(registry);
+    let options = runner::RunOptions { jobs, timeout, ..selection.options(config, registry.year()) };
+
+    let mut answers = match Answers::load(answers::DEFAULT_PATH) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Couldn't load {}: {}", answers::DEFAULT_PATH, e);
+            return false;
+        }
     };
 
-    pb.set_message(&format!("1: {}", console::style("...").red()));
-    let (part_1, data) = part_1_fn(&input);
+    match format {
+        Format::Pretty => println!(" --- \u{1f384} \u{2728} Advent of Code {} \u{2728} \u{1f384} --- ", registry.year()),
+        Format::Csv => println!("{}", output::csv_header()),
+        Format::Json => ()
+    }
+
+    let progress = progress && format == Format::Pretty;
+    let reports = runner::run_all(inputs, &solvers, &answers, &options, progress, |report| match format {
+        Format::Pretty if progress => (),
+        Format::Pretty => println!("{}", output::plain_line(report)),
+        Format::Json => println!("{}", output::json_line(report)),
+        Format::Csv => println!("{}", output::csv_row(report))
+    });
 
-    pb.set_message(&format!("1: {}, 2: {}", console::style(&part_1).green(), console::style("...").red()));
-    let part_2 = part_2_fn(&input, data);
+    let mut passed = !reports.iter().any(|report| report.failed());
+    if record {
+        for (report, &solver) in reports.iter().zip(&solvers) {
+            if options.is_variant(solver) {
+                eprintln!("Not recording day {}, its parameters are changed", report.day);
+                continue;
+            }
+            for part in report.parts.iter().flatten() {
+                if let Ok(ref answer) = part.answer {
+                    answers.set(registry.year(), report.day, part.number, answer);
+                }
+            }
+        }
 
-    pb.finish_with_message(&format!("1: {}, 2: {}", console::style(&part_1).green(), console::style(&part_2).green()));
+        match answers.save() {
+            Ok(()) => eprintln!("Recorded {} answers to {}", answers.len(), answers::DEFAULT_PATH),
+            Err(e) => {
+                eprintln!("Couldn't save {}: {}", answers::DEFAULT_PATH, e);
+                passed = false;
+            }
+        }
+    }
+    passed
 }
 
-fn main() {
-    let session = std::env::var("AOC_SESSION").expect("Expected AoC session in $AOC_SESSION");
-
-    println!(" --- \u{1f384} \u{2728} Advent of Code 2018 \u{2728} \u{1f384} --- ");
-
-    let mut days: Vec<Box<dyn Fn(&str)>> = vec![
-        Box::new(|s| run(s, 1, "Chronal Calibration", day1::part1, day1::part2)),
-        Box::new(|s| run(s, 2, "Inventory Management System", day2::part1, day2::part2)),
-        Box::new(|s| run(s, 3, "No Matter How You Slice It", day3::part1, day3::part2)),
-        Box::new(|s| run(s, 4, "Repose Record", day4::part1, day4::part2)),
-        Box::new(|s| run(s, 5, "Alchemical Reduction", day5::part1, day5::part2)),
-        Box::new(|s| run(s, 6, "Chronal Coordinates", day6::part1, day6::part2)),
-        Box::new(|s| run(s, 7, "The Sum of Its Parts", day7::part1, day7::part2)),
-        Box::new(|s| run(s, 8, "Memory Maneuver", day8::part1, day8::part2)),
-        Box::new(|s| run(s, 9, "Marble Mania", day9::part1, day9::part2)),
-        Box::new(|s| run(s, 10, "The Stars Align", day10::part1, day10::part2)),
-        Box::new(|s| run(s, 11, "Chronal Charge", day11::part1, day11::part2)),
-        Box::new(|s| run(s, 12, "Subterranean Sustainability", day12::part1, day12::part2)),
-        Box::new(|s| run(s, 13, "Mine Cart Madness", day13::part1, day13::part2)),
-        Box::new(|s| run(s, 14, "Chocolate Charts", day14::part1, day14::part2)),
-        Box::new(|s| run(s, 15, "Beverage Bandits", day15::part1, day15::part2)),
-        Box::new(|s| run(s, 16, "Chronal Classification", day16::part1, day16::part2))
-    ];
-
-    if let Some(day) = std::env::args().nth(1) {
-        let day_num: usize = day.parse::<usize>().unwrap();
-
-        if day_num < 1 || day_num > days.len() {
-            eprintln!("Day {} not found.", day_num);
+fn bench_days(inputs: &InputCache, registry: &Registry, config: &Config, args: &[String]) {
+    let mut selection = Selection::default();
+    let mut runs = 10;
+    let mut warmup = 2;
+    let mut threshold = 10.0;
+    let mut save = false;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if selection.take(arg, &mut args) { continue; }
+        match arg.as_str() {
+            "--runs" => runs = number("--runs", &mut args),
+            "--warmup" => warmup = number("--warmup", &mut args),
+            "--threshold" => threshold = number("--threshold", &mut args),
+            "--save" => save = true,
+            _ => usage_error(format!("unknown option {}", arg))
+        }
+    }
+    if selection.part.is_some() {
+        usage_error("bench always times both parts");
+    }
+    if save && selection.input.is_some() {
+        usage_error("only timings for the real inputs can be saved");
+    }
+
+    let solvers = selection.solvers(registry);
+    let options = selection.options(config, registry.year());
+    if save && solvers.iter().any(|&solver| options.is_variant(solver)) {
+        usage_error("only timings with the puzzles' own parameters can be saved");
+    }
+
+    let mut baseline = match Baseline::load(bench::DEFAULT_BASELINE_PATH) {
+        Ok(baseline) => baseline,
+        Err(e) => {
+            eprintln!("Couldn't load {}: {}", bench::DEFAULT_BASELINE_PATH, e);
             return;
         }
-        (days.get_mut(day_num - 1).unwrap())(&session);
-    } else {
-        for day in days.iter_mut() {
-            (day)(&session);
+    };
+
+    for solver in solvers {
+        println!("{}", console::style(format!("Day {}: {}", solver.day(), solver.title())).cyan());
+
+        let input = match options.input {
+            Some(ref input) => Ok(input.clone()),
+            None => inputs.get(solver.day(), &indicatif::ProgressBar::hidden())
+        };
+        let result = input
+            .and_then(|input| bench::bench(solver, &input, &options.params(solver.day()), warmup, runs));
+        let day_bench = match result {
+            Ok(day_bench) => day_bench,
+            Err(e) => {
+                println!("    {}", console::style(&e.kind).red());
+                continue;
+            }
+        };
+
+        for (phase, stats) in &day_bench.phases {
+            let comparison = match baseline.get(registry.year(), day_bench.day, *phase) {
+                Some(before) => {
+                    let change = bench::change(before, stats.median) * 100.0;
+                    let text = format!("{:+.1}% vs {}", change, Elapsed(before));
+                    if change > threshold {
+                        format!("{} {}", console::style(text).red(), console::style("regression").red().bold())
+                    } else if change < -threshold {
+                        console::style(text).green().to_string()
+                    } else {
+                        text
+                    }
+                },
+                None => String::new()
+            };
+
+            println!("    {:<6} min {:>9}  median {:>9}  mean {:>9}  stddev {:>9}  {}",
+                phase.name(), Elapsed(stats.min), Elapsed(stats.median), Elapsed(stats.mean), Elapsed(stats.stddev), comparison);
+        }
+
+        if save {
+            baseline.record(registry.year(), &day_bench);
+        }
+    }
+
+    if save {
+        match baseline.save() {
+            Ok(()) => println!("Saved baseline to {}", bench::DEFAULT_BASELINE_PATH),
+            Err(e) => eprintln!("Couldn't save {}: {}", bench::DEFAULT_BASELINE_PATH, e)
+        }
+    }
+}
+
+fn manage_inputs(inputs: &InputCache, registry: &Registry, args: &[String]) {
+    let select = |args: &[String]| match args {
+        [] => registry.iter().collect::<Vec<_>>(),
+        days => registry.select(&days.join(",")).unwrap_or_else(|e| usage_error(e))
+    };
+
+    match args.first().map(|a| a.as_str()) {
+        Some("list") => {
+            let cached = match inputs.list() {
+                Ok(cached) => cached,
+                Err(e) => { eprintln!("Couldn't read {}: {}", inputs.dir().display(), e); return; }
+            };
+            for solver in registry.iter() {
+                match cached.iter().find(|c| c.day == solver.day()) {
+                    Some(input) => println!("Day {:>2}: {:<35} {} ({} bytes)",
+                        solver.day(), solver.title(), console::style("cached").green(), input.size),
+                    None => println!("Day {:>2}: {:<35} {}", solver.day(), solver.title(), console::style("missing").yellow())
+                }
+            }
+            let legacy = inputs.legacy().unwrap_or_default();
+            if !legacy.is_empty() {
+                println!("{} input(s) from before years were tracked are still in {}, inputs adopt moves them into {}",
+                    legacy.len(), inputs.root().display(), inputs.dir().display());
+            }
+        },
+        Some("verify") => {
+            for solver in select(&args[1..]) {
+                let status = if !inputs.is_cached(solver.day()) {
+                    console::style("missing".to_string()).yellow()
+                } else {
+                    match inputs.verify(solver) {
+                        Ok(()) => console::style("ok".to_string()).green(),
+                        Err(e) => console::style(e.kind.to_string()).red()
+                    }
+                };
+                println!("Day {:>2}: {:<35} {}", solver.day(), solver.title(), status);
+            }
+        },
+        Some("clear") => {
+            let result = match args.len() {
+                1 => inputs.clear_all(),
+                _ => select(&args[1..]).into_iter()
+                    .map(|solver| inputs.clear(solver.day()))
+                    .try_fold(0, |removed, cleared| cleared.map(|cleared| removed + cleared as usize))
+            };
+            match result {
+                Ok(removed) => println!("Removed {} cached input(s) from {}", removed, inputs.dir().display()),
+                Err(e) => eprintln!("Couldn't clear {}: {}", inputs.dir().display(), e)
+            }
+        },
+        Some("adopt") => match inputs.adopt_legacy() {
+            Ok(adopted) if adopted.is_empty() => println!("There are no inputs from before years were tracked to move"),
+            Ok(adopted) => for input in adopted {
+                println!("Moved day {} to {}", input.day, input.path.display());
+            },
+            Err(e) => eprintln!("Couldn't move the old inputs into {}: {}", inputs.dir().display(), e)
+        },
+        _ => usage_error("inputs takes list, verify [days], clear [days] or adopt")
+    }
+}
+
+fn check_examples(registry: &Registry, args: &[String]) {
+    let mut selection = Selection::default();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if !selection.take(arg, &mut args) || selection.part.is_some() || selection.input.is_some() || !selection.params.is_empty() {
+            usage_error(format!("{} doesn't work with check-examples", arg));
+        }
+    }
+
+    let mut failed = 0;
+    for solver in selection.solvers(registry) {
+        let checks = examples::check(solver);
+        let passed = checks.iter().filter(|c| c.passed()).count();
+        let summary = format!("{}/{} passed", passed, checks.len());
+        println!("Day {:>2}: {:<35} {}", solver.day(), solver.title(), if passed == checks.len() {
+            console::style(summary).green()
+        } else {
+            console::style(summary).red()
+        });
+
+        for check in checks.iter().filter(|c| !c.passed()) {
+            let got = match check.answer {
+                Ok(ref answer) => format!("got {:?}", answer),
+                Err(ref e) => e.kind.to_string()
+            };
+            println!("    example {} part {}: expected {:?}, {}", check.example, check.part, check.expected, console::style(got).red());
+        }
+        failed += checks.len() - passed;
+    }
+
+    if failed > 0 {
+        std::process::exit(1);
+    }
+}
+
+// The checkout new and watch change and rebuild
+fn crate_root() -> PathBuf {
+    let cwd = std::env::current_dir().unwrap_or_else(|e| usage_error(format!("couldn't tell where this is: {}", e)));
+    scaffold::find_root(&cwd)
+        .unwrap_or_else(|| usage_error(format!("{} isn't inside a checkout, there's no Cargo.toml with src/days above it", cwd.display())))
+}
+
+fn new_day(year: usize, args: &[String]) {
+    let (day, title) = match args {
+        [day, title] => match day.parse::<usize>() {
+            Ok(day @ 1..=registry::LAST_DAY) => (day, title),
+            _ => usage_error(format!("{:?} isn't a day from 1 to {}", day, registry::LAST_DAY))
+        },
+        _ => usage_error("new takes a day and a title, like `new 17 \"Reservoir Research\"`")
+    };
+
+    match scaffold::create(scaffold::days_dir(crate_root()), year, day, title) {
+        Ok(path) => println!("Created {}", path.display()),
+        Err(ref e) if e.kind() == std::io::ErrorKind::AlreadyExists => usage_error(format!("day {} of {} already exists", day, year)),
+        Err(e) => {
+            eprintln!("Couldn't create day {}: {}", day, e);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn generate_input(registry: &Registry, args: &[String]) {
+    let mut day = None;
+    let mut size = None;
+    let mut seed = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--size" => size = Some(number("--size", &mut args)),
+            "--seed" => seed = Some(number("--seed", &mut args)),
+            _ if !arg.starts_with('-') && day.is_none() => day = Some(arg),
+            _ => usage_error(format!("unknown option {}", arg))
         }
     }
+
+    let day = day.unwrap_or_else(|| usage_error("gen takes a day, like `gen 3 --size 100`"));
+    let found = day.parse().ok()
+        .and_then(|day| registry.get(day))
+        .and_then(|solver| Some((solver.day(), solver.generator()?)));
+    let (day, generator) = found.unwrap_or_else(|| usage_error(format!("there's no generator for day {} of {}", day, registry.year())));
+    let size = size.unwrap_or(generator.default_size);
+    // Printed so an interesting input can be made again
+    let seed = seed.unwrap_or_else(|| {
+        std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).map_or(0, |t| t.as_nanos() as u64)
+    });
+
+    eprintln!("Day {} with seed {}, size {} ({})", day, seed, size, generator.size);
+    print!("{}", generator.generate(size, seed));
+}
+
+// Answers from before watch rebuilt and restarted itself, as `<part> <answer>` lines
+const WATCH_PREVIOUS_VAR: &str = "AOC_WATCH_PREVIOUS";
+
+fn watch_day(inputs: &InputCache, registry: &Registry, config: &Config, progress: bool, args: &[String]) {
+    let mut selection = Selection::default();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if !selection.take(arg, &mut args) || selection.all {
+            usage_error(format!("{} doesn't work with watch", arg));
+        }
+    }
+    if selection.input.as_ref().is_some_and(|path| path == "-") {
+        usage_error("watch can't read its input from stdin");
+    }
+
+    let solver = match selection.solvers(registry)[..] {
+        [solver] => solver,
+        _ => usage_error("watch takes exactly one day")
+    };
+
+    let root = crate_root();
+    let source = scaffold::year_dir(scaffold::days_dir(&root), registry.year()).join(format!("day{}.rs", solver.day()));
+    let input = selection.input.as_ref().map_or_else(|| inputs.path(solver.day()), PathBuf::from);
+    let mut watcher = Watcher::new(vec![input.clone(), source.clone()]);
+
+    let mut previous = BTreeMap::new();
+    for line in std::env::var(WATCH_PREVIOUS_VAR).unwrap_or_default().lines() {
+        if let Some((part, answer)) = line.split_once(' ') {
+            previous.insert(part.parse::<usize>().unwrap_or_default(), answers::unescape(answer));
+        }
+    }
+
+    loop {
+        // Read every time, since the answers might have been recorded in the meantime
+        let answers = Answers::load(answers::DEFAULT_PATH).unwrap_or_else(|e| {
+            eprintln!("Couldn't load {}: {}", answers::DEFAULT_PATH, e);
+            std::process::exit(1);
+        });
+        let options = selection.options(config, registry.year());
+
+        let pb = if progress { runner::spinner(solver) } else { indicatif::ProgressBar::hidden() };
+        let report = runner::run(inputs, solver, &answers, &options, &pb);
+        if !progress {
+            println!("{}", output::plain_line(&report));
+        }
+
+        // Failed parts keep their last answer, so fixing them compares against what worked before
+        for part in report.parts.iter().flatten() {
+            let answer = match part.answer {
+                Ok(ref answer) => answer,
+                Err(_) => continue
+            };
+            match previous.insert(part.number, answer.clone()) {
+                None => (),
+                Some(ref before) if before == answer => println!("    part {}: unchanged", part.number),
+                Some(ref before) if before.contains('\n') || answer.contains('\n') => {
+                    println!("    part {}: changed", part.number);
+                    for line in watch::diff(before, answer) {
+                        match line {
+                            watch::Line::Same(line) => println!("      {}", line),
+                            watch::Line::Removed(line) => println!("    {}", console::style(format!("- {}", line)).red()),
+                            watch::Line::Added(line) => println!("    {}", console::style(format!("+ {}", line)).green())
+                        }
+                    }
+                },
+                Some(before) => println!("    part {}: {}, was {}", part.number, console::style(answer).cyan(), console::style(before).dim())
+            }
+        }
+
+        println!("{}", console::style(format!("Watching {} and {}, ctrl-c to stop", input.display(), source.display())).dim());
+        let changed = watcher.wait();
+        let names = changed.iter().map(|path| path.display().to_string()).collect::<Vec<_>>();
+        println!("\n{} changed", names.join(" and "));
+
+        if changed.contains(&source) {
+            restart(&root, &previous);
+        }
+    }
+}
+
+// Rebuilds the checkout with cargo and swaps this process for the new build, keeping the previous answers.
+// If the build fails the old build keeps watching, so the next save gets another go
+fn restart(root: &Path, previous: &BTreeMap<usize, String>) {
+    let profile = if cfg!(debug_assertions) { "debug" } else { "release" };
+    let mut build = std::process::Command::new(std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    build.arg("build").current_dir(root);
+    if profile == "release" {
+        build.arg("--release");
+    }
+    match build.status() {
+        Ok(status) if status.success() => (),
+        Ok(_) => { eprintln!("{}", console::style("Build failed, still running the old build").red()); return; },
+        Err(e) => { eprintln!("Couldn't run cargo: {}", e); return; }
+    }
+
+    // Not necessarily where this binary is, it might have been copied or installed somewhere else
+    let target = std::env::var_os("CARGO_TARGET_DIR").map_or_else(|| root.join("target"), PathBuf::from);
+    let exe = root.join(target).join(profile).join(format!("{}{}", env!("CARGO_PKG_NAME"), std::env::consts::EXE_SUFFIX));
+
+    let carried = previous.iter().map(|(part, answer)| format!("{} {}\n", part, answers::escape(answer))).collect::<String>();
+    let mut command = std::process::Command::new(&exe);
+    command.args(std::env::args_os().skip(1)).env(WATCH_PREVIOUS_VAR, carried);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        let e = command.exec();
+        eprintln!("Couldn't restart {}: {}", exe.display(), e);
+    }
+    #[cfg(not(unix))]
+    {
+        // Without exec the new build runs as a child, and this one only waits to pass on how it exited
+        match command.status() {
+            Ok(status) => std::process::exit(status.code().unwrap_or(1)),
+            Err(e) => eprintln!("Couldn't restart {}: {}", exe.display(), e)
+        }
+    }
+}
+
+fn submit_answer(inputs: &InputCache, registry: &Registry, submitter: &dyn Submitter, offline: bool, args: &[String]) {
+    let mut positional = vec![];
+    let mut answer = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--answer" => answer = Some(value("--answer", &mut args).clone()),
+            _ if !arg.starts_with('-') => positional.push(arg),
+            _ => usage_error(format!("unknown option {}", arg))
+        }
+    }
+
+    let (day, part) = match (positional.first().map(|d| d.parse()), positional.get(1).map(|p| p.parse())) {
+        (Some(Ok(day)), Some(Ok(part @ 1..=2))) if positional.len() == 2 => (day, part),
+        _ => usage_error("submit takes a day and a part, like `submit 5 2`")
+    };
+    if offline {
+        usage_error("can't submit answers while offline");
+    }
+
+    let year = registry.year();
+    let solver = registry.get(day).unwrap_or_else(|| usage_error(format!("day {} of {} isn't solved yet", day, year)));
+
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let result = inputs.get(day, &indicatif::ProgressBar::hidden())
+                .and_then(|input| solver.parse(&input, &Params::new()))
+                .and_then(|parsed| if part == 1 { solver.part1(parsed.as_ref()) } else { solver.part2(parsed.as_ref()) });
+            match result {
+                Ok(answer) => answer,
+                Err(e) => { eprintln!("{}", console::style(e).red()); return; }
+            }
+        }
+    };
+    println!("Day {} part {}: {}", day, part, console::style(&answer).cyan());
+
+    let mut history = match History::load(submit::DEFAULT_HISTORY_PATH) {
+        Ok(history) => history,
+        Err(e) => { eprintln!("Couldn't load {}: {}", submit::DEFAULT_HISTORY_PATH, e); return; }
+    };
+    if let Some(reason) = history.refuse(year, day, part, &answer) {
+        println!("Not submitting, {}", console::style(reason).yellow());
+        return;
+    }
+
+    let outcome = match submitter.submit(year, day, part, &answer) {
+        Ok(outcome) => outcome,
+        Err(e) => { eprintln!("{}", console::style(e).red()); return; }
+    };
+    match outcome {
+        Outcome::Correct => println!("{}", console::style("That's the right answer!").green().bold()),
+        Outcome::TooHigh => println!("{}", console::style("Wrong, too high.").red()),
+        Outcome::TooLow => println!("{}", console::style("Wrong, too low.").red()),
+        Outcome::Wrong => println!("{}", console::style("Wrong.").red()),
+        Outcome::RateLimited { wait: Some(wait) } =>
+            println!("{}", console::style(format!("Answered too recently, try again in {}s.", wait.as_secs())).yellow()),
+        Outcome::RateLimited { wait: None } => println!("{}", console::style("Answered too recently, try again later.").yellow()),
+        Outcome::AlreadySolved => println!("{}", console::style("This part is already solved.").yellow())
+    }
+
+    history.record(year, day, part, &answer, outcome.clone());
+    if let Err(e) = history.save() {
+        eprintln!("Couldn't save {}: {}", submit::DEFAULT_HISTORY_PATH, e);
+    }
+
+    // A correct answer is as good as a recorded one
+    if outcome == Outcome::Correct {
+        let saved = Answers::load(answers::DEFAULT_PATH).and_then(|mut answers| {
+            answers.set(year, day, part, &answer);
+            answers.save()
+        });
+        if let Err(e) = saved {
+            eprintln!("Couldn't save {}: {}", answers::DEFAULT_PATH, e);
+        }
+    }
+}
+
+fn main() {
+    // Global options, which can appear anywhere on the command line
+    let mut offline = std::env::var_os("AOC_OFFLINE").is_some();
+    let mut cache_dir = std::env::var("AOC_CACHE_DIR").unwrap_or_else(|_| inputs::DEFAULT_DIR.to_string());
+    let mut config_path = std::env::var("AOC_CONFIG").ok();
+    let mut year = std::env::var("AOC_YEAR").ok();
+    let mut no_progress = false;
+    let mut help = false;
+
+    let mut args = vec![];
+    let mut all_args = std::env::args().skip(1);
+    while let Some(arg) = all_args.next() {
+        match arg.as_str() {
+            "--offline" => offline = true,
+            "--cache-dir" => cache_dir = all_args.next().unwrap_or_else(|| usage_error("--cache-dir needs a value")),
+            "--config" => config_path = Some(all_args.next().unwrap_or_else(|| usage_error("--config needs a value"))),
+            "--year" => year = Some(all_args.next().unwrap_or_else(|| usage_error("--year needs a value"))),
+            // console already works out whether to colour from the terminal and $CLICOLOR, so auto leaves it be
+            "--color" => match all_args.next().as_deref() {
+                Some("auto") => (),
+                Some("always") => console::set_colors_enabled(true),
+                Some("never") => console::set_colors_enabled(false),
+                _ => usage_error("--color takes auto, always or never")
+            },
+            "--no-progress" => no_progress = true,
+            "-h" | "--help" => help = true,
+            _ => args.push(arg)
+        }
+    }
+
+    // Spinners redraw themselves with escape codes, which only make sense on a terminal
+    let progress = !no_progress && console::user_attended();
+
+    // A config that was asked for by name has to be there, the default one doesn't
+    if let Some(ref path) = config_path {
+        if !Path::new(path).exists() {
+            usage_error(format!("Couldn't find config {}", path));
+        }
+    }
+    let config_path = config_path.unwrap_or_else(|| config::DEFAULT_PATH.to_string());
+    let config = Config::load(&config_path).unwrap_or_else(|e| {
+        eprintln!("Couldn't load {}: {}", config_path, e);
+        std::process::exit(2);
+    });
+
+    // The command line, then the environment, then the config, then whatever year is newest
+    let year = match year {
+        Some(year) => year.parse().unwrap_or_else(|_| usage_error(format!("{:?} isn't a year", year))),
+        None => config.year.unwrap_or_else(registry::latest_year)
+    };
+    if year < registry::FIRST_YEAR {
+        usage_error(format!("Advent of Code started in {}, there's no {}", registry::FIRST_YEAR, year));
+    }
+    let registry = Registry::new(year);
+    if help {
+        print_usage(&registry);
+        return;
+    }
+
+    // The session is only needed once something actually has to be fetched
+    let session = std::env::var("AOC_SESSION").ok();
+    let inputs = InputCache::new(cache_dir, year, offline, Box::new(HttpFetcher::new(session.clone())));
+
+    let passed = match args.first().map(|a| a.as_str()) {
+        Some("bench") => { bench_days(&inputs, &registry, &config, &args[1..]); true },
+        Some("run") => run_days(&inputs, &registry, &config, &args[1..], false, progress),
+        Some("record") => run_days(&inputs, &registry, &config, &args[1..], true, progress),
+        Some("inputs") => { manage_inputs(&inputs, &registry, &args[1..]); true },
+        Some("check-examples") => { check_examples(&registry, &args[1..]); true },
+        Some("submit") => { submit_answer(&inputs, &registry, &HttpFetcher::new(session), offline, &args[1..]); true },
+        Some("new") => { new_day(year, &args[1..]); true },
+        Some("watch") => { watch_day(&inputs, &registry, &config, progress, &args[1..]); true },
+        Some("gen") => { generate_input(&registry, &args[1..]); true },
+        _ => run_days(&inputs, &registry, &config, &args, false, progress)
+    };
+    // So scripts and CI notice when an answer is wrong
+    if !passed {
+        std::process::exit(1);
+    }
 }
@@ -0,0 +1,146 @@
+use std::str::FromStr;
+
+use crate::answers::Verification;
+use crate::bench::Elapsed;
+use crate::runner::{self, Part, Report};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    // Spinners and colours for a human at a terminal, or a plain line per day when stdout isn't one
+    Pretty,
+    // One JSON object per line and day
+    Json,
+    Csv
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Format, String> {
+        match s {
+            "pretty" => Ok(Format::Pretty),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("unknown output format {:?}, expected pretty, json or csv", s))
+        }
+    }
+}
+
+fn status(part: &Part) -> &'static str {
+    match (&part.answer, &part.verification) {
+        (Err(_), _) => "error",
+        (Ok(_), Verification::Verified) => "verified",
+        (Ok(_), Verification::Wrong { .. }) => "wrong",
+        (Ok(_), Verification::Unknown) => "unknown"
+    }
+}
+
+fn expected(part: &Part) -> Option<&str> {
+    match part.verification {
+        Verification::Wrong { ref expected } => Some(expected),
+        _ => None
+    }
+}
+
+fn millis(part: &Part) -> f64 {
+    part.duration.as_secs_f64() * 1000.0
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c)
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn json_option(s: Option<&str>) -> String {
+    s.map_or_else(|| "null".to_string(), json_string)
+}
+
+fn json_part(part: &Part) -> String {
+    format!("{{\"answer\":{},\"expected\":{},\"status\":{},\"duration_ms\":{:.3},\"error\":{}}}",
+        json_option(part.answer.as_ref().ok().map(|a| a.as_str())),
+        json_option(expected(part)),
+        json_string(status(part)),
+        millis(part),
+        json_option(part.answer.as_ref().err().map(|e| e.kind.to_string()).as_deref()))
+}
+
+pub fn json_line(report: &Report) -> String {
+    let parts = match report.parts {
+        Ok(ref parts) => parts.iter().map(|p| format!("\"part{}\":{}", p.number, json_part(p))).collect::<Vec<_>>(),
+        Err(_) => vec![]
+    };
+
+    let mut fields = vec![
+        format!("\"day\":{}", report.day),
+        format!("\"title\":{}", json_string(report.title))
+    ];
+    fields.extend(parts);
+    fields.push(format!("\"error\":{}", json_option(report.parts.as_ref().err().map(|e| e.kind.to_string()).as_deref())));
+
+    format!("{{{}}}", fields.join(","))
+}
+
+// What a spinner finishes on, plus how long each part took, for logs where spinners can't be drawn
+pub fn plain_line(report: &Report) -> String {
+    let result = match report.parts {
+        Ok(ref parts) => parts.iter()
+            .map(|p| format!("{}: {} ({})", p.number, runner::styled(p), Elapsed(p.duration)))
+            .collect::<Vec<_>>()
+            .join(", "),
+        Err(ref e) => console::style(&e.kind).red().to_string()
+    };
+    format!("Day {:>2}: {:<35} {}", report.day, report.title, result)
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+pub fn csv_header() -> String {
+    "day,title,part1,part1_expected,part1_status,part1_ms,part2,part2_expected,part2_status,part2_ms,error".to_string()
+}
+
+pub fn csv_row(report: &Report) -> String {
+    let mut fields = vec![report.day.to_string(), csv_field(report.title)];
+    let mut errors = vec![];
+
+    match report.parts {
+        // Parts that weren't run still get their (empty) columns
+        Ok(ref parts) => for number in 1..=2 {
+            let part = match parts.iter().find(|p| p.number == number) {
+                Some(part) => part,
+                None => { fields.extend((0..4).map(|_| String::new())); continue; }
+            };
+            fields.push(csv_field(part.answer.as_ref().map(|a| a.as_str()).unwrap_or("")));
+            fields.push(csv_field(expected(part).unwrap_or("")));
+            fields.push(status(part).to_string());
+            fields.push(format!("{:.3}", millis(part)));
+            if let Err(ref e) = part.answer {
+                errors.push(format!("part {}: {}", part.number, e.kind));
+            }
+        },
+        Err(ref e) => {
+            fields.extend((0..8).map(|_| String::new()));
+            errors.push(e.kind.to_string());
+        }
+    }
+
+    fields.push(csv_field(&errors.join("; ")));
+    fields.join(",")
+}
@@ -0,0 +1,64 @@
+use std::collections::BTreeMap;
+
+use crate::error::{Error, ErrorKind, Result};
+
+// Numbers a puzzle statement fixes that its examples change, like day 7's number of workers.
+// Days declare their defaults, so only overrides need to be set here.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, u64>
+}
+
+// What a day declares for each of its params, the description is what --help shows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: u64,
+    pub description: &'static str
+}
+
+impl Params {
+    pub fn new() -> Params {
+        Params::default()
+    }
+
+    pub fn from_pairs(pairs: &[(&str, u64)]) -> Params {
+        let mut params = Params::new();
+        for &(name, value) in pairs {
+            params.set(name, value);
+        }
+        params
+    }
+
+    pub fn set(&mut self, name: &str, value: u64) {
+        self.values.insert(name.to_string(), value);
+    }
+
+    // Only for params the day declared, which `resolve` guarantees are there
+    pub fn get(&self, name: &str) -> u64 {
+        *self.values.get(name).unwrap_or_else(|| panic!("parameter {} wasn't declared", name))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u64)> {
+        self.values.iter().map(|(name, value)| (name.as_str(), *value))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    // The declared defaults with these overrides on top
+    pub fn resolve(&self, declared: &[Param]) -> Result<Params> {
+        let mut resolved = Params::new();
+        for param in declared {
+            resolved.set(param.name, param.default);
+        }
+        for (name, value) in self.iter() {
+            if !declared.iter().any(|param| param.name == name) {
+                return Err(Error::from(ErrorKind::UnknownParam(name.to_string())));
+            }
+            resolved.set(name, value);
+        }
+        Ok(resolved)
+    }
+}
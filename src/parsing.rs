@@ -0,0 +1,155 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+// Errors here are plain reasons without a line number, so they go through OnLine like any other:
+//
+//     let (id, x, y) = parsing::scan::<(usize, usize, usize)>("#{} @ {},{}", line).on_line(i + 1, line)?;
+
+// Every integer in the line, with a leading minus sign making it negative
+pub fn ints<T>(line: &str) -> Result<Vec<T>, String> where T: FromStr, T::Err: Display {
+    let mut numbers = vec![];
+    let mut chars = line.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        let negative = c == '-' && chars.peek().is_some_and(|(_, next)| next.is_ascii_digit());
+        if !c.is_ascii_digit() && !negative { continue; }
+
+        let mut end = start + c.len_utf8();
+        while let Some(&(i, d)) = chars.peek() {
+            if !d.is_ascii_digit() { break; }
+            end = i + d.len_utf8();
+            chars.next();
+        }
+        let text = &line[start..end];
+        numbers.push(text.parse().map_err(|e| format!("couldn't read {:?} at column {}: {}", text, column(line, start), e))?);
+    }
+    Ok(numbers)
+}
+
+// Pulls the `{}` fields out of a line that should otherwise look exactly like the pattern.
+// Fields are trimmed before parsing, so padding like `< 3, -1>` doesn't get in the way
+pub fn scan<T: Fields>(pattern: &str, line: &str) -> Result<T, String> {
+    let literals = pattern.split("{}").collect::<Vec<_>>();
+    assert_eq!(literals.len() - 1, T::COUNT, "{:?} should have {} fields", pattern, T::COUNT);
+
+    let mut fields = vec![];
+    let mut rest = line.strip_prefix(literals[0])
+        .ok_or_else(|| format!("expected {:?} at column 1", literals[0]))?;
+    for (i, literal) in literals.iter().enumerate().skip(1) {
+        let at = line.len() - rest.len();
+        let end = if i == literals.len() - 1 {
+            // The last field runs up to whatever ends the line
+            rest.strip_suffix(literal).map(|field| field.len())
+        } else {
+            assert!(!literal.is_empty(), "{:?} has fields with nothing between them", pattern);
+            rest.find(literal)
+        };
+        let end = end.ok_or_else(|| format!("expected {:?} after column {}", literal, column(line, at)))?;
+
+        fields.push((column(line, at), &rest[..end]));
+        rest = &rest[end + literal.len()..];
+    }
+
+    T::from_fields(&fields)
+}
+
+// Paragraphs separated by one or more blank lines, along with the line each one starts on
+pub fn blocks(input: &str) -> Vec<(usize, &str)> {
+    let mut blocks = vec![];
+    let mut current = None;
+    let (mut offset, mut end) = (0, 0);
+    for (i, line) in input.split('\n').enumerate() {
+        if line.trim().is_empty() {
+            if let Some((line_no, start)) = current.take() { blocks.push((line_no, &input[start..end])); }
+        } else {
+            current.get_or_insert((i + 1, offset));
+            end = offset + line.trim_end().len();
+        }
+        offset += line.len() + 1;
+    }
+    if let Some((line_no, start)) = current { blocks.push((line_no, &input[start..end])); }
+    blocks
+}
+
+fn column(line: &str, byte: usize) -> usize {
+    line[..byte].chars().count() + 1
+}
+
+// Something scan can fill in, given each field's text and the column it starts at
+pub trait Fields: Sized {
+    const COUNT: usize;
+
+    fn from_fields(fields: &[(usize, &str)]) -> Result<Self, String>;
+}
+
+fn field<T>((column, text): (usize, &str)) -> Result<T, String> where T: FromStr, T::Err: Display {
+    text.trim().parse().map_err(|e| format!("couldn't read {:?} at column {}: {}", text.trim(), column, e))
+}
+
+macro_rules! tuple_fields {
+    ($count:expr; $($t:ident $i:tt),*) => {
+        impl<$($t),*> Fields for ($($t,)*) where $($t: FromStr, $t::Err: Display),* {
+            const COUNT: usize = $count;
+
+            fn from_fields(fields: &[(usize, &str)]) -> Result<Self, String> {
+                Ok(($(field::<$t>(fields[$i])?,)*))
+            }
+        }
+    };
+}
+
+tuple_fields!(1; A 0);
+tuple_fields!(2; A 0, B 1);
+tuple_fields!(3; A 0, B 1, C 2);
+tuple_fields!(4; A 0, B 1, C 2, D 3);
+tuple_fields!(5; A 0, B 1, C 2, D 3, E 4);
+tuple_fields!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::OnLine;
+
+    #[test]
+    fn ints_reads_negatives() {
+        assert_eq!(ints::<isize>("position=< 9, -1> velocity=<-10,  2>").unwrap(), [9, -1, -10, 2]);
+        // A dash that isn't in front of a digit is just a dash
+        assert_eq!(ints::<isize>("1-2 - 3 --4").unwrap(), [1, -2, 3, -4]);
+        assert_eq!(ints::<usize>("no numbers here").unwrap(), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn ints_reports_the_column() {
+        assert_eq!(ints::<usize>("é 7, -3").unwrap_err(), "couldn't read \"-3\" at column 6: invalid digit found in string");
+        assert_eq!(ints::<u8>("1 300").unwrap_err(), "couldn't read \"300\" at column 3: number too large to fit in target type");
+    }
+
+    #[test]
+    fn scan_fills_in_fields() {
+        let (id, x, y, w, h) = scan::<(usize, usize, usize, usize, usize)>("#{} @ {},{}: {}x{}", "#123 @ 3,2: 5x4").unwrap();
+        assert_eq!((id, x, y, w, h), (123, 3, 2, 5, 4));
+        let (x, y) = scan::<(isize, isize)>("<{},{}>", "< 3, -1>").unwrap();
+        assert_eq!((x, y), (3, -1));
+    }
+
+    #[test]
+    fn scan_errors_have_line_and_column() {
+        let error = |line: &str| scan::<(usize, usize)>("{} players; last marble is worth {} points", line)
+            .on_line(4, line)
+            .unwrap_err()
+            .to_string();
+
+        assert_eq!(error("9 players; last marble is worth x points"),
+                   "line 4: couldn't read \"x\" at column 33: invalid digit found in string in \"9 players; last marble is worth x points\"");
+        assert_eq!(error("9 players; first marble is worth 25 points"),
+                   "line 4: expected \" players; last marble is worth \" after column 1 in \"9 players; first marble is worth 25 poin…\"");
+        assert_eq!(error("9 players; last marble is worth 25"),
+                   "line 4: expected \" points\" after column 33 in \"9 players; last marble is worth 25\"");
+        assert!(scan::<(usize,)>("#{}", "123").unwrap_err().contains("column 1"));
+    }
+
+    #[test]
+    fn blocks_skip_blank_lines() {
+        let input = "a\nb\n\n\nc\n  \nd\n";
+        assert_eq!(blocks(input), [(1, "a\nb"), (5, "c"), (7, "d")]);
+    }
+}
@@ -0,0 +1,87 @@
+// Xorshift, which is plenty for making up inputs and needs no crate. The same seed always
+// gives the same numbers, so anything made from one can be made again
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        // Zero would stay zero forever
+        Rng(seed.wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    // From lo up to and including hi
+    pub fn range(&mut self, lo: usize, hi: usize) -> usize {
+        assert!(lo <= hi, "nothing between {} and {}", lo, hi);
+        lo + self.up_to((hi - lo) as u64) as usize
+    }
+
+    pub fn signed(&mut self, lo: isize, hi: isize) -> isize {
+        assert!(lo <= hi, "nothing between {} and {}", lo, hi);
+        // The span can be too big for an isize, but never for a usize
+        lo.wrapping_add(self.up_to(hi.wrapping_sub(lo) as usize as u64) as isize)
+    }
+
+    // From 0 up to and including max, which can be every u64 there is
+    fn up_to(&mut self, max: u64) -> u64 {
+        match max.checked_add(1) {
+            Some(count) => self.next_u64() % count,
+            None => self.next_u64()
+        }
+    }
+
+    pub fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        assert!(!items.is_empty(), "picking from nothing");
+        items[self.range(0, items.len() - 1)]
+    }
+
+    pub fn chance(&mut self, percent: usize) -> bool {
+        self.range(1, 100) <= percent
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0, i));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ranges_include_both_ends() {
+        let mut rng = Rng::new(1);
+        let rolls = (0..1000).map(|_| rng.range(3, 6)).collect::<Vec<_>>();
+        assert!(rolls.iter().all(|roll| (3..=6).contains(roll)));
+        assert!(rolls.contains(&3) && rolls.contains(&6));
+        let rolls = (0..1000).map(|_| rng.signed(-2, 2)).collect::<Vec<_>>();
+        assert!(rolls.iter().all(|roll| (-2..=2).contains(roll)));
+        assert!(rolls.contains(&-2) && rolls.contains(&2));
+        assert_eq!(rng.range(4, 4), 4);
+    }
+
+    #[test]
+    fn ranges_as_wide_as_the_type() {
+        let mut rng = Rng::new(2);
+        for _ in 0..100 {
+            rng.range(0, usize::MAX);
+            rng.range(1, usize::MAX);
+            rng.signed(isize::MIN, isize::MAX);
+            assert!(rng.signed(isize::MIN, -1) < 0);
+            assert!(rng.range(usize::MAX - 1, usize::MAX) >= usize::MAX - 1);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "nothing between 5 and 4")]
+    fn backwards_ranges_panic() {
+        Rng::new(3).range(5, 4);
+    }
+}
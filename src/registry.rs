@@ -0,0 +1,102 @@
+use std::collections::BTreeSet;
+
+use crate::solver::AnySolver;
+use crate::days;
+
+pub const FIRST_YEAR: usize = 2015;
+
+// Every year's puzzles run from day 1 to this one
+pub const LAST_DAY: usize = 25;
+
+// Files written before years were tracked only ever had this year's days in them
+pub const LEGACY_YEAR: usize = 2018;
+
+// The solved days of one year
+pub struct Registry {
+    year: usize,
+    solvers: Vec<&'static dyn AnySolver>
+}
+
+// Every year with at least one day, oldest first
+pub fn years() -> Vec<usize> {
+    let mut years = days::ALL.iter().map(|&(year, _)| year).collect::<Vec<_>>();
+    years.dedup();
+    years
+}
+
+// What runs when no year is picked
+pub fn latest_year() -> usize {
+    years().last().cloned().unwrap_or(LEGACY_YEAR)
+}
+
+impl Registry {
+    pub fn new(year: usize) -> Registry {
+        // Days are unit structs, so they can live forever and be handed to threads that might outlive a run
+        let solvers = days::ALL.iter().filter(|&&(y, _)| y == year).map(|&(_, solver)| solver).collect();
+        Registry { year, solvers }
+    }
+
+    pub fn year(&self) -> usize {
+        self.year
+    }
+
+    pub fn get(&self, day: usize) -> Option<&'static dyn AnySolver> {
+        self.iter().find(|s| s.day() == day)
+    }
+
+    // Days like `1-5,9,15`, in day order and without repeats
+    pub fn select(&self, spec: &str) -> Result<Vec<&'static dyn AnySolver>, String> {
+        let mut days = BTreeSet::new();
+        for item in spec.split(',').map(|i| i.trim()).filter(|i| !i.is_empty()) {
+            // Checked before a range is expanded, so a typo can't turn into billions of days
+            let number = |n: &str| match n.trim().parse::<usize>() {
+                Ok(day @ 1..=LAST_DAY) => Ok(day),
+                Ok(day) => Err(format!("there's no day {}, days go from 1 to {}", day, LAST_DAY)),
+                Err(_) => Err(format!("{:?} isn't a day number", n.trim()))
+            };
+            match item.find('-') {
+                Some(dash) => {
+                    let (from, to) = (number(&item[..dash])?, number(&item[dash + 1..])?);
+                    if from > to {
+                        return Err(format!("range {:?} goes backwards", item));
+                    }
+                    days.extend(from..=to);
+                },
+                None => { days.insert(number(item)?); }
+            }
+        }
+        if days.is_empty() {
+            return Err(format!("{:?} doesn't name any days", spec));
+        }
+
+        days.into_iter()
+            .map(|day| self.get(day).ok_or_else(|| format!("day {} of {} isn't solved yet", day, self.year)))
+            .collect()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item=&'static dyn AnySolver> + '_ {
+        self.solvers.iter().cloned()
+    }
+
+    pub fn len(&self) -> usize {
+        self.solvers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.solvers.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_outside_a_year_are_rejected() {
+        let registry = Registry::new(LEGACY_YEAR);
+        assert_eq!(registry.select("1-9999999999").err().unwrap(), "there's no day 9999999999, days go from 1 to 25");
+        assert_eq!(registry.select("0").err().unwrap(), "there's no day 0, days go from 1 to 25");
+        assert_eq!(registry.select("5-3").err().unwrap(), "range \"5-3\" goes backwards");
+        assert_eq!(registry.select("3,1-2,2").unwrap().iter().map(|s| s.day()).collect::<Vec<_>>(), [1, 2, 3]);
+    }
+}
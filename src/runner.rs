@@ -0,0 +1,299 @@
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::sync::{Arc, Mutex, Once};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+
+use crate::answers::{Answers, Verification};
+use crate::cancel;
+use crate::error::{Error, ErrorKind, Result};
+use crate::inputs::InputCache;
+use crate::params::Params;
+use crate::solver::AnySolver;
+
+#[derive(Debug)]
+pub struct Part {
+    pub number: usize,
+    pub answer: Result<String>,
+    pub verification: Verification,
+    pub duration: Duration
+}
+
+#[derive(Debug)]
+pub struct Report {
+    pub day: usize,
+    pub title: &'static str,
+    // Err if the input couldn't be loaded or parsed, so neither part ran
+    pub parts: Result<Vec<Part>>
+}
+
+impl Report {
+    // Whether anything went wrong: the input, an error in either part or an answer that doesn't match
+    pub fn failed(&self) -> bool {
+        match self.parts {
+            Ok(ref parts) => parts.iter().any(|p| p.answer.is_err() || matches!(p.verification, Verification::Wrong { .. })),
+            Err(_) => true
+        }
+    }
+}
+
+// Coloured by how the answer checks out, colours are left out whenever console has them turned off
+pub(crate) fn styled(part: &Part) -> String {
+    match (&part.answer, &part.verification) {
+        (Ok(answer), Verification::Verified) => console::style(answer).green().to_string(),
+        (Ok(answer), Verification::Wrong { expected }) =>
+            format!("{} (expected {})", console::style(answer).red(), console::style(expected).green()),
+        (Ok(answer), Verification::Unknown) => console::style(answer).yellow().to_string(),
+        (Err(e), _) => console::style(&e.kind).red().to_string()
+    }
+}
+
+// How to run the selected days
+#[derive(Debug, Clone)]
+pub struct RunOptions {
+    pub parts: Vec<usize>,
+    // Used instead of the cached input, e.g. to try a solver on an example
+    pub input: Option<String>,
+    pub jobs: usize,
+    // Wall-clock limit for parsing and solving a day, not counting fetching the input
+    pub timeout: Option<Duration>,
+    // Overrides by day, for running variants of a puzzle
+    pub params: BTreeMap<usize, Params>
+}
+
+impl RunOptions {
+    pub fn params(&self, day: usize) -> Params {
+        self.params.get(&day).cloned().unwrap_or_default()
+    }
+
+    // The known answers only hold for the real input with the puzzle's own params. Setting
+    // one to what the day declares anyway doesn't change anything
+    pub fn is_variant(&self, solver: &dyn AnySolver) -> bool {
+        self.input.is_some() || self.params(solver.day()).iter().any(|(name, value)| {
+            solver.params().iter().find(|param| param.name == name).is_none_or(|param| param.default != value)
+        })
+    }
+}
+
+impl Default for RunOptions {
+    fn default() -> RunOptions {
+        RunOptions { parts: vec![1, 2], input: None, jobs: 1, timeout: Some(Duration::from_secs(60)), params: BTreeMap::new() }
+    }
+}
+
+// What the thread solving a day reports back, as it happens
+enum Event {
+    Parsed(Result<()>),
+    Solved { answer: Result<String>, duration: Duration }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast_ref::<&str>().map_or("unknown cause", |m| m).to_string()
+    }
+}
+
+// Runs `f`, turning a panic into an error
+fn isolate<T>(day: usize, f: impl FnOnce() -> Result<T>) -> Result<T> {
+    panic::catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|payload| Err(Error::from(ErrorKind::Panicked(panic_message(payload))).on_day(day)))
+}
+
+// Panics in day threads end up in the report, so the default hook would only garble the progress display
+fn quiet_day_panics() {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let default = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !thread::current().name().is_some_and(|name| name.starts_with("day ")) {
+                default(info);
+            }
+        }));
+    });
+}
+
+// A day being solved on its own thread. Dropping it cancels the day, so one that times out
+// doesn't keep running in the background
+struct Solving {
+    rx: Receiver<Event>,
+    cancelled: Arc<AtomicBool>
+}
+
+impl Drop for Solving {
+    fn drop(&mut self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+}
+
+fn solve(solver: &'static dyn AnySolver, input: String, params: Params, parts: Vec<usize>) -> Solving {
+    quiet_day_panics();
+
+    let (tx, rx) = mpsc::channel();
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let flag = cancelled.clone();
+    let day = solver.day();
+    thread::Builder::new()
+        .name(format!("day {}", day))
+        .spawn(move || {
+            cancel::install(flag);
+            let parsed = match isolate(day, || solver.parse(&input, &params)) {
+                Ok(parsed) => parsed,
+                Err(e) => { let _ = tx.send(Event::Parsed(Err(e))); return; }
+            };
+            let _ = tx.send(Event::Parsed(Ok(())));
+
+            for number in parts {
+                let start = Instant::now();
+                let answer = isolate(day, || if number == 1 { solver.part1(parsed.as_ref()) } else { solver.part2(parsed.as_ref()) });
+                // Nobody is listening any more if the day timed out
+                if tx.send(Event::Solved { answer, duration: start.elapsed() }).is_err() { return; }
+            }
+        })
+        .expect("couldn't start a thread for the day");
+    Solving { rx, cancelled }
+}
+
+fn receive(rx: &Receiver<Event>, day: usize, started: Instant, timeout: Option<Duration>) -> Result<Event> {
+    let result = match timeout {
+        Some(timeout) => rx.recv_timeout(timeout.saturating_sub(started.elapsed())),
+        None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected)
+    };
+    result.map_err(|e| match e {
+        RecvTimeoutError::Timeout => Error::from(ErrorKind::TimedOut(started.elapsed())).on_day(day),
+        RecvTimeoutError::Disconnected => Error::from(ErrorKind::Panicked("day thread stopped".to_string())).on_day(day)
+    })
+}
+
+fn progress(parts: &[Part], running: Option<usize>) -> String {
+    let mut shown = parts.iter().map(|p| format!("{}: {}", p.number, styled(p))).collect::<Vec<_>>();
+    if let Some(number) = running {
+        shown.push(format!("{}: {}", number, console::style("...").red()));
+    }
+    shown.join(", ")
+}
+
+pub fn spinner(solver: &dyn AnySolver) -> ProgressBar {
+    let pb = indicatif::ProgressBar::new_spinner();
+    pb.set_style(ProgressStyle::default_spinner().template("{elapsed:>3} {spinner} {prefix:<35!.cyan} {msg}"));
+    pb.set_prefix(&format!("Day {}: {}", solver.day(), solver.title()));
+    pb.enable_steady_tick(100);
+    pb
+}
+
+// Bars for parallel runs all exist from the start, so their elapsed time would include the wait in the queue
+fn queued(solver: &dyn AnySolver) -> ProgressBar {
+    let pb = indicatif::ProgressBar::new_spinner();
+    pb.set_style(ProgressStyle::default_spinner().template("    {spinner} {prefix:<35!.cyan} {msg}"));
+    pb.set_prefix(&format!("Day {}: {}", solver.day(), solver.title()));
+    pb.set_message("queued");
+    pb
+}
+
+// Progress goes to `pb`, which can be hidden when only the report is wanted
+pub fn run(inputs: &InputCache, solver: &'static dyn AnySolver, answers: &Answers, options: &RunOptions, pb: &ProgressBar) -> Report {
+    let mut report = Report { day: solver.day(), title: solver.title(), parts: Ok(vec![]) };
+
+    let input = match options.input {
+        Some(ref input) => Ok(input.clone()),
+        None => inputs.get(solver.day(), pb)
+    };
+    let started = Instant::now();
+    let rx = input.map(|input| {
+        pb.set_message("parsing...");
+        solve(solver, input, options.params(solver.day()), options.parts.clone())
+    });
+
+    // Problems with the input mean neither part can run, so they take up the whole line
+    let parsed = rx.and_then(|solving| match receive(&solving.rx, solver.day(), started, options.timeout)? {
+        Event::Parsed(result) => result.map(|_| solving),
+        Event::Solved { .. } => unreachable!("parts are only solved after parsing")
+    });
+    let solving = match parsed {
+        Ok(solving) => solving,
+        Err(e) => {
+            pb.finish_with_message(&console::style(&e.kind).red().to_string());
+            report.parts = Err(e);
+            return report;
+        }
+    };
+
+    let answers = if options.is_variant(solver) { None } else { Some(answers) };
+
+    let mut parts: Vec<Part> = vec![];
+    for &number in &options.parts {
+        pb.set_message(&progress(&parts, Some(number)));
+
+        let part_started = Instant::now();
+        let (answer, duration) = match receive(&solving.rx, solver.day(), started, options.timeout) {
+            Ok(Event::Solved { answer, duration }) => (answer, duration),
+            Ok(Event::Parsed(_)) => unreachable!("input is only parsed once"),
+            Err(e) => (Err(e), part_started.elapsed())
+        };
+        let verification = match (&answer, answers) {
+            (Ok(answer), Some(answers)) => answers.verify(inputs.year(), solver.day(), number, answer),
+            _ => Verification::Unknown
+        };
+        parts.push(Part { number, answer, verification, duration });
+    }
+    pb.finish_with_message(&progress(&parts, None));
+
+    report.parts = Ok(parts);
+    report
+}
+
+// Runs the days on up to `options.jobs` threads, handing each report to `emit` in the order of `solvers`.
+// Without `progress` nothing is drawn while the days run, so all the output comes from `emit`
+pub fn run_all(inputs: &InputCache, solvers: &[&'static dyn AnySolver], answers: &Answers, options: &RunOptions, progress: bool,
+               mut emit: impl FnMut(&Report) + Send) -> Vec<Report> {
+    if options.jobs <= 1 {
+        return solvers.iter()
+            .map(|&solver| {
+                let pb = if progress { spinner(solver) } else { ProgressBar::hidden() };
+                let report = run(inputs, solver, answers, options, &pb);
+                emit(&report);
+                report
+            })
+            .collect();
+    }
+
+    let multi = MultiProgress::new();
+    let bars = solvers.iter()
+        .map(|&solver| if progress { multi.add(queued(solver)) } else { ProgressBar::hidden() })
+        .collect::<Vec<_>>();
+
+    let next = AtomicUsize::new(0);
+    // Reports that are done and how many of them have been emitted, with `emit` locked in alongside
+    let done = Mutex::new((solvers.iter().map(|_| None).collect::<Vec<Option<Report>>>(), 0, emit));
+
+    thread::scope(|scope| {
+        for _ in 0..options.jobs.min(solvers.len()) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::SeqCst);
+                if i >= solvers.len() { break; }
+
+                if progress { bars[i].enable_steady_tick(100); }
+                let report = run(inputs, solvers[i], answers, options, &bars[i]);
+
+                let mut done = done.lock().unwrap();
+                let (ref mut reports, ref mut emitted, ref mut emit) = *done;
+                reports[i] = Some(report);
+                while let Some(Some(report)) = reports.get(*emitted) {
+                    emit(report);
+                    *emitted += 1;
+                }
+            });
+        }
+
+        if progress {
+            multi.join().ok();
+        }
+    });
+
+    done.into_inner().unwrap().0.into_iter().map(|r| r.expect("every day was run")).collect()
+}
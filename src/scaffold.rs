@@ -0,0 +1,61 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+// Where `new` puts days, a directory per year, build.rs picks them up from here
+pub fn days_dir(root: impl AsRef<Path>) -> PathBuf {
+    root.as_ref().join("src").join("days")
+}
+
+// The checkout to work on: the nearest directory from `from` upwards with a Cargo.toml and days.
+// This has to be looked for, a binary that was copied or installed can be far from where it was built
+pub fn find_root(from: impl AsRef<Path>) -> Option<PathBuf> {
+    from.as_ref().ancestors()
+        .find(|dir| dir.join("Cargo.toml").is_file() && days_dir(dir).is_dir())
+        .map(Path::to_path_buf)
+}
+
+pub fn year_dir(days_dir: impl AsRef<Path>, year: usize) -> PathBuf {
+    days_dir.as_ref().join(format!("y{}", year))
+}
+
+// A day that compiles and runs but has no answers yet, with an empty example to fill in
+pub fn template(day: usize, title: &str) -> String {
+    format!(r#"use crate::error::{{Error, Result}};
+use crate::examples::Example;
+
+solver!(Day{day}, {day}, {title:?}, Vec<String>);
+
+pub fn parse(input: &str) -> Result<Vec<String>> {{
+    Ok(input.lines().map(|line| line.to_string()).collect())
+}}
+
+pub fn part1(_lines: &[String]) -> Result<String> {{
+    Err(Error::no_solution())
+}}
+
+pub fn part2(_lines: &[String]) -> Result<String> {{
+    Err(Error::no_solution())
+}}
+
+const EXAMPLES: &[Example] = &[
+    Example {{
+        input: "
+",
+        part1: None,
+        part2: None,
+        params: &[]
+    }}
+];
+"#, day = day, title = title)
+}
+
+// Refuses to touch a day that's already there. The first day of a year starts its directory
+pub fn create(days_dir: impl AsRef<Path>, year: usize, day: usize, title: &str) -> io::Result<PathBuf> {
+    let dir = year_dir(days_dir, year);
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("day{}.rs", day));
+    let mut file = OpenOptions::new().write(true).create_new(true).open(&path)?;
+    file.write_all(template(day, title).as_bytes())?;
+    Ok(path)
+}
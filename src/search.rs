@@ -0,0 +1,272 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::hash::Hash;
+
+// Everything a breadth-first search reached, and how
+pub struct Bfs<N> {
+    start: N,
+    distances: HashMap<N, usize>,
+    predecessors: HashMap<N, N>
+}
+
+impl<N: Eq + Hash + Clone> Bfs<N> {
+    pub fn distance(&self, node: &N) -> Option<usize> {
+        self.distances.get(node).cloned()
+    }
+
+    // From the start to node, both included
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.distances.contains_key(node) { return None; }
+        Some(walk_back(&self.predecessors, &self.start, node.clone()))
+    }
+
+    pub fn reached(&self) -> impl Iterator<Item=(&N, usize)> {
+        self.distances.iter().map(|(node, d)| (node, *d))
+    }
+}
+
+pub fn bfs<N, I>(start: N, mut neighbors: impl FnMut(&N) -> I) -> Bfs<N>
+    where N: Eq + Hash + Clone, I: IntoIterator<Item=N>
+{
+    let mut distances = HashMap::new();
+    let mut predecessors = HashMap::new();
+    distances.insert(start.clone(), 0);
+
+    let mut frontier = vec![start.clone()];
+    let mut distance = 0;
+    while !frontier.is_empty() {
+        distance += 1;
+        let mut next = vec![];
+        for node in &frontier {
+            for n in neighbors(node) {
+                if distances.contains_key(&n) { continue; }
+                distances.insert(n.clone(), distance);
+                predecessors.insert(n.clone(), node.clone());
+                next.push(n);
+            }
+        }
+        frontier = next;
+    }
+
+    Bfs { start, distances, predecessors }
+}
+
+// The closest node that is_target accepts and how far away it is. If several are equally
+// close the smallest one wins, so with reading-ordered points it's the first in reading order
+pub fn nearest<N, I>(start: N, mut neighbors: impl FnMut(&N) -> I, mut is_target: impl FnMut(&N) -> bool) -> Option<(N, usize)>
+    where N: Eq + Hash + Clone + Ord, I: IntoIterator<Item=N>
+{
+    let mut seen = HashSet::new();
+    seen.insert(start.clone());
+
+    let mut frontier = vec![start];
+    let mut distance = 0;
+    while !frontier.is_empty() {
+        // Checked before expanding, so targets on the last layer that can be reached still count
+        if let Some(found) = frontier.iter().filter(|n| is_target(n)).min() {
+            return Some((found.clone(), distance));
+        }
+
+        let mut next = vec![];
+        for node in &frontier {
+            next.extend(neighbors(node).into_iter().filter(|n| seen.insert(n.clone())));
+        }
+        frontier = next;
+        distance += 1;
+    }
+    None
+}
+
+// The cheapest path to a goal, from start to goal both included, and what it costs.
+// successors gives each neighbor with the cost of moving there
+pub fn dijkstra<N, I>(start: N, successors: impl FnMut(&N) -> I, is_goal: impl FnMut(&N) -> bool) -> Option<(Vec<N>, usize)>
+    where N: Eq + Hash + Clone + Ord, I: IntoIterator<Item=(N, usize)>
+{
+    astar(start, successors, |_| 0, is_goal)
+}
+
+// Dijkstra steered by heuristic, which must never overestimate the remaining cost
+// or the path it finds might not be the cheapest
+pub fn astar<N, I>(start: N, mut successors: impl FnMut(&N) -> I, mut heuristic: impl FnMut(&N) -> usize,
+                   mut is_goal: impl FnMut(&N) -> bool) -> Option<(Vec<N>, usize)>
+    where N: Eq + Hash + Clone + Ord, I: IntoIterator<Item=(N, usize)>
+{
+    let mut costs = HashMap::new();
+    let mut predecessors = HashMap::new();
+    costs.insert(start.clone(), 0);
+
+    // Ties go to the smallest node, to keep the path the same from run to run
+    let mut queue = BinaryHeap::new();
+    queue.push(Reverse((heuristic(&start), 0, start.clone())));
+
+    while let Some(Reverse((_, cost, node))) = queue.pop() {
+        // Already reached some cheaper way
+        if cost > costs[&node] { continue; }
+        if is_goal(&node) {
+            return Some((walk_back(&predecessors, &start, node), cost));
+        }
+
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if costs.get(&next).is_some_and(|&c| c <= next_cost) { continue; }
+
+            costs.insert(next.clone(), next_cost);
+            predecessors.insert(next.clone(), node.clone());
+            queue.push(Reverse((next_cost + heuristic(&next), next_cost, next)));
+        }
+    }
+    None
+}
+
+fn walk_back<N: Eq + Hash + Clone>(predecessors: &HashMap<N, N>, start: &N, end: N) -> Vec<N> {
+    let mut path = vec![end];
+    while path.last() != Some(start) {
+        let previous = predecessors[path.last().unwrap()].clone();
+        path.push(previous);
+    }
+    path.reverse();
+    path
+}
+
+// Kahn's algorithm, a node at a time so the caller decides when each one is finished.
+// A node is ready once everything before it is finished, and ready nodes come out smallest first
+#[derive(Clone)]
+pub struct Kahn<N: Ord> {
+    // How many unfinished nodes each node still waits on, until it's handed out
+    waiting_on: HashMap<N, usize>,
+    after: HashMap<N, Vec<N>>,
+    ready: BinaryHeap<Reverse<N>>,
+    unfinished: usize
+}
+
+impl<N: Eq + Hash + Clone + Ord> Kahn<N> {
+    // Each edge is (before, after)
+    pub fn new(edges: impl IntoIterator<Item=(N, N)>) -> Kahn<N> {
+        let mut waiting_on = HashMap::new();
+        let mut after = HashMap::<N, Vec<N>>::new();
+        for (first, second) in edges {
+            waiting_on.entry(first.clone()).or_insert(0);
+            *waiting_on.entry(second.clone()).or_insert(0) += 1;
+            after.entry(first).or_default().push(second);
+        }
+
+        let ready = waiting_on.iter()
+            .filter(|(_, &count)| count == 0)
+            .map(|(node, _)| Reverse(node.clone()))
+            .collect();
+        let unfinished = waiting_on.len();
+        Kahn { waiting_on, after, ready, unfinished }
+    }
+
+    // The smallest ready node, which is then no longer ready but not finished either
+    pub fn pop(&mut self) -> Option<N> {
+        let Reverse(node) = self.ready.pop()?;
+        self.waiting_on.remove(&node);
+        Some(node)
+    }
+
+    pub fn finish(&mut self, node: &N) {
+        self.unfinished -= 1;
+        for next in self.after.get(node).into_iter().flatten() {
+            let count = self.waiting_on.get_mut(next).expect("finished a node twice");
+            *count -= 1;
+            if *count == 0 { self.ready.push(Reverse(next.clone())); }
+        }
+    }
+
+    // False while anything is left, which once nothing is ready means there's a cycle
+    pub fn is_done(&self) -> bool {
+        self.unfinished == 0
+    }
+}
+
+// None if the edges have a cycle
+pub fn topological_sort<N: Eq + Hash + Clone + Ord>(edges: impl IntoIterator<Item=(N, N)>) -> Option<Vec<N>> {
+    let mut kahn = Kahn::new(edges);
+    let mut order = vec![];
+    while let Some(node) = kahn.pop() {
+        kahn.finish(&node);
+        order.push(node);
+    }
+    if kahn.is_done() { Some(order) } else { None }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::{Bounds, Point};
+    use crate::grid::Grid;
+    use crate::random::Rng;
+
+    // The open squares next to p, walls are false
+    fn open_neighbors(grid: &Grid<bool>, p: Point) -> Vec<Point> {
+        p.neighbors4().filter(|&n| grid.at(n) == Some(&true)).collect()
+    }
+
+    #[test]
+    fn bfs_measures_and_walks_back() {
+        let grid = Grid::parse("...\n##.\n...", |c| Some(c == '.')).unwrap();
+        let found = bfs(Point::new(0, 0), |&p| open_neighbors(&grid, p));
+        assert_eq!(found.distance(&Point::new(0, 2)), Some(6));
+        assert_eq!(found.distance(&Point::new(0, 1)), None);
+        assert_eq!(found.path_to(&Point::new(2, 1)).unwrap(), [Point::new(0, 0), Point::new(1, 0), Point::new(2, 0), Point::new(2, 1)]);
+        assert_eq!(found.reached().count(), 7);
+    }
+
+    #[test]
+    fn nearest_breaks_ties_in_reading_order() {
+        let open = Bounds { min: Point::new(0, 0), max: Point::new(4, 4) };
+        let neighbors = |p: &Point| p.neighbors4().filter(|&n| open.contains(n)).collect::<Vec<_>>();
+        let start = Point::new(2, 2);
+
+        // All two steps away, the one on the top row comes first
+        let targets = [Point::new(1, 3), Point::new(3, 1), Point::new(2, 0), Point::new(4, 2)];
+        assert_eq!(nearest(start, neighbors, |p| targets.contains(p)), Some((Point::new(2, 0), 2)));
+        // Then left to right within a row
+        let targets = [Point::new(3, 1), Point::new(1, 1), Point::new(0, 4)];
+        assert_eq!(nearest(start, neighbors, |p| targets.contains(p)), Some((Point::new(1, 1), 2)));
+
+        assert_eq!(nearest(start, neighbors, |&p| p == start), Some((start, 0)));
+        assert_eq!(nearest(start, neighbors, |&p| p == Point::new(5, 5)), None);
+    }
+
+    #[test]
+    fn dijkstra_and_astar_agree() {
+        let mut rng = Rng::new(16);
+        let mut reached = 0;
+        for _ in 0..50 {
+            // Entering a square costs its digit, walls are 0
+            let grid = Grid::from_fn(8, 8, |_| if rng.chance(20) { 0 } else { rng.range(1, 9) });
+            let (start, goal) = (Point::new(0, 0), Point::new(7, 7));
+            let successors = |p: &Point| p.neighbors4()
+                .filter_map(|n| grid.at(n).filter(|&&cost| cost > 0).map(|&cost| (n, cost)))
+                .collect::<Vec<_>>();
+
+            let plain = dijkstra(start, successors, |&p| p == goal);
+            let steered = astar(start, successors, |p| p.manhattan(goal), |&p| p == goal);
+            assert_eq!(plain.as_ref().map(|(_, cost)| cost), steered.as_ref().map(|(_, cost)| cost));
+            reached += plain.is_some() as usize;
+
+            for (path, cost) in plain.into_iter().chain(steered) {
+                assert_eq!((path[0], path[path.len() - 1]), (start, goal));
+                assert!(path.windows(2).all(|step| step[0].manhattan(step[1]) == 1));
+                assert_eq!(path[1..].iter().map(|&p| grid.at(p).unwrap()).sum::<usize>(), cost);
+            }
+        }
+        // Walls shouldn't cut the goal off every time
+        assert!(reached > 25, "only {} of the grids had a path", reached);
+    }
+
+    #[test]
+    fn topological_sort_takes_the_smallest_ready_node() {
+        let edges = vec![('C', 'A'), ('C', 'F'), ('A', 'B'), ('A', 'D'), ('B', 'E'), ('D', 'E'), ('F', 'E')];
+        assert_eq!(topological_sort(edges).unwrap().into_iter().collect::<String>(), "CABDFE");
+    }
+
+    #[test]
+    fn topological_sort_rejects_a_cycle() {
+        assert_eq!(topological_sort(vec![(1, 2), (2, 3), (3, 1)]), None);
+        assert_eq!(topological_sort(vec![(0, 1), (1, 2), (2, 1)]), None);
+        assert_eq!(topological_sort(Vec::<(u8, u8)>::new()), Some(vec![]));
+    }
+}
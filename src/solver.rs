@@ -0,0 +1,124 @@
+use std::any::Any;
+
+use crate::error::Result;
+use crate::examples::Example;
+use crate::generate::Generator;
+use crate::params::{Param, Params};
+
+pub trait Solver {
+    const DAY: usize;
+    const TITLE: &'static str;
+
+    // See Params
+    const PARAMS: &'static [Param] = &[];
+    const EXAMPLES: &'static [Example] = &[];
+    // For gen, days without one just don't have random inputs
+    const GENERATOR: Option<&'static Generator> = None;
+
+    // Whatever both parts share, so either part can be run on its own
+    type Parsed: 'static;
+
+    // Only gets the params the day declared, with defaults filled in
+    fn parse(input: &str, params: &Params) -> Result<Self::Parsed>;
+    fn part1(parsed: &Self::Parsed) -> Result<String>;
+    fn part2(parsed: &Self::Parsed) -> Result<String>;
+}
+
+// Object-safe view of a Solver, so days with different Parsed types can live in one list
+// (and be shared with worker threads)
+pub trait AnySolver: Sync {
+    fn day(&self) -> usize;
+    fn title(&self) -> &'static str;
+    fn params(&self) -> &'static [Param];
+    fn examples(&self) -> &'static [Example];
+    fn generator(&self) -> Option<&'static Generator>;
+    fn parse(&self, input: &str, params: &Params) -> Result<Box<dyn Any>>;
+    fn part1(&self, parsed: &dyn Any) -> Result<String>;
+    fn part2(&self, parsed: &dyn Any) -> Result<String>;
+}
+
+// Declares the Solver for a day module in terms of its free parse/part1/part2 functions
+// and its EXAMPLES. Days with params list them with their defaults and descriptions, like
+// `workers = 5 "elves working at once"`, and their parse takes them too. Days that can make up
+// inputs say what the size means and its default, like `generate: size = 1000 "frequency changes"`,
+// and have a `generate(rng, size)` function.
+macro_rules! solver {
+    (@generator) => { None };
+    (@generator $default:literal $size:literal) => {
+        Some(&crate::generate::Generator::new($size, $default, generate))
+    };
+    ($name:ident, $day:expr, $title:expr, $parsed:ty $(, generate: size = $default:literal $size:literal)?) => {
+        solver!(@impl $name, $day, $title, $parsed, [], |input, _| parse(input), [$($default $size)?]);
+    };
+    ($name:ident, $day:expr, $title:expr, $parsed:ty, params: [$($param:ident = $param_default:literal $description:literal),*]
+        $(, generate: size = $default:literal $size:literal)?) => {
+        solver!(@impl $name, $day, $title, $parsed, [$(crate::params::Param {
+            name: stringify!($param),
+            default: $param_default,
+            description: $description
+        }),*], |input, params| parse(input, params), [$($default $size)?]);
+    };
+    (@impl $name:ident, $day:expr, $title:expr, $parsed:ty, [$($param:expr),*], $parse:expr, [$($generator:tt)*]) => {
+        pub struct $name;
+
+        impl crate::solver::Solver for $name {
+            const DAY: usize = $day;
+            const TITLE: &'static str = $title;
+            const PARAMS: &'static [crate::params::Param] = &[$($param),*];
+            const EXAMPLES: &'static [crate::examples::Example] = EXAMPLES;
+            const GENERATOR: Option<&'static crate::generate::Generator> = solver!(@generator $($generator)*);
+
+            type Parsed = $parsed;
+
+            fn parse(input: &str, params: &crate::params::Params) -> crate::error::Result<$parsed> {
+                let parse_with: fn(&str, &crate::params::Params) -> crate::error::Result<$parsed> = $parse;
+                parse_with(input, params)
+            }
+
+            fn part1(parsed: &$parsed) -> crate::error::Result<String> {
+                part1(parsed)
+            }
+
+            fn part2(parsed: &$parsed) -> crate::error::Result<String> {
+                part2(parsed)
+            }
+        }
+    };
+}
+
+impl<S: Solver + Sync> AnySolver for S {
+    fn day(&self) -> usize {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn params(&self) -> &'static [Param] {
+        S::PARAMS
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        S::EXAMPLES
+    }
+
+    fn generator(&self) -> Option<&'static Generator> {
+        S::GENERATOR
+    }
+
+    fn parse(&self, input: &str, params: &Params) -> Result<Box<dyn Any>> {
+        match params.resolve(S::PARAMS).and_then(|params| S::parse(input, &params)) {
+            Ok(parsed) => Ok(Box::new(parsed)),
+            Err(e) => Err(e.on_day(S::DAY))
+        }
+    }
+
+    fn part1(&self, parsed: &dyn Any) -> Result<String> {
+        S::part1(parsed.downcast_ref().expect("parsed input from another day")).map_err(|e| e.on_day(S::DAY))
+    }
+
+    fn part2(&self, parsed: &dyn Any) -> Result<String> {
+        S::part2(parsed.downcast_ref().expect("parsed input from another day")).map_err(|e| e.on_day(S::DAY))
+    }
+}
@@ -0,0 +1,178 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::answers::{escape, split_year, unescape};
+use crate::error::{Error, OnLine, Result};
+
+pub const DEFAULT_HISTORY_PATH: &str = "submissions.txt";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    // Wrong, but the site didn't say in which direction
+    Wrong,
+    RateLimited { wait: Option<Duration> },
+    // The part was solved before, possibly outside of this tool
+    AlreadySolved
+}
+
+impl Outcome {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too-high",
+            Outcome::TooLow => "too-low",
+            Outcome::Wrong => "wrong",
+            Outcome::RateLimited { .. } => "rate-limited",
+            Outcome::AlreadySolved => "already-solved"
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Outcome> {
+        match name {
+            "correct" => Some(Outcome::Correct),
+            "too-high" => Some(Outcome::TooHigh),
+            "too-low" => Some(Outcome::TooLow),
+            "wrong" => Some(Outcome::Wrong),
+            "rate-limited" => Some(Outcome::RateLimited { wait: None }),
+            "already-solved" => Some(Outcome::AlreadySolved),
+            _ => None
+        }
+    }
+
+    // Whether the site actually judged the answer
+    pub fn is_judged(&self) -> bool {
+        !matches!(self, Outcome::RateLimited { .. } | Outcome::AlreadySolved)
+    }
+}
+
+pub trait Submitter {
+    fn submit(&self, year: usize, day: usize, part: usize, answer: &str) -> Result<Outcome>;
+}
+
+lazy_static! {
+    static ref WAIT: Regex = Regex::new(r"(?:(\d+)m )?(\d+)s left to wait").unwrap();
+}
+
+// The answer endpoint replies with an HTML page, the verdict is somewhere in its <article>
+pub fn parse_response(body: &str) -> Result<Outcome> {
+    let article = match (body.find("<article"), body.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &body[start..end],
+        _ => body
+    };
+
+    if article.contains("That's the right answer") {
+        Ok(Outcome::Correct)
+    } else if article.contains("That's not the right answer") {
+        Ok(if article.contains("too high") {
+            Outcome::TooHigh
+        } else if article.contains("too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Wrong
+        })
+    } else if article.contains("You gave an answer too recently") {
+        let wait = WAIT.captures(article).map(|caps| {
+            let minutes = caps.get(1).map_or(0, |m| m.as_str().parse().unwrap_or(0));
+            let seconds = caps[2].parse().unwrap_or(0);
+            Duration::from_secs(minutes * 60 + seconds)
+        });
+        Ok(Outcome::RateLimited { wait })
+    } else if article.contains("You don't seem to be solving the right level") {
+        Ok(Outcome::AlreadySolved)
+    } else if article.contains("Please log in") || article.contains("please identify yourself") {
+        Err(Error::fetch(None, "session is invalid or expired, log in again and update $AOC_SESSION"))
+    } else {
+        Err(Error::fetch(None, "couldn't make sense of the response"))
+    }
+}
+
+pub struct Submission {
+    pub year: usize,
+    pub day: usize,
+    pub part: usize,
+    pub outcome: Outcome,
+    pub answer: String
+}
+
+// Every attempt so far, one `<year> <day> <part> <outcome> <answer>` per line
+pub struct History {
+    path: PathBuf,
+    submissions: Vec<Submission>
+}
+
+impl History {
+    pub fn load(path: impl AsRef<Path>) -> Result<History> {
+        let path = path.as_ref().to_path_buf();
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e.into())
+        };
+
+        let mut submissions = vec![];
+        for (i, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() { continue; }
+
+            let (year, rest) = split_year(line);
+            let mut fields = rest.splitn(4, ' ');
+            let day = fields.next().unwrap().parse().on_line(i + 1, line)?;
+            let part = fields.next().on_line(i + 1, line)?.parse().on_line(i + 1, line)?;
+            let outcome = fields.next().and_then(Outcome::from_name).on_line(i + 1, line)?;
+            let answer = unescape(fields.next().on_line(i + 1, line)?);
+            submissions.push(Submission { year, day, part, outcome, answer });
+        }
+
+        Ok(History { path, submissions })
+    }
+
+    pub fn submissions(&self, year: usize, day: usize, part: usize) -> impl Iterator<Item = &Submission> {
+        self.submissions.iter().filter(move |s| s.year == year && s.day == day && s.part == part)
+    }
+
+    // Why `answer` shouldn't be sent, judging by earlier attempts
+    pub fn refuse(&self, year: usize, day: usize, part: usize, answer: &str) -> Option<String> {
+        let judged = self.submissions(year, day, part).filter(|s| s.outcome.is_judged()).collect::<Vec<_>>();
+
+        if let Some(correct) = judged.iter().find(|s| s.outcome == Outcome::Correct) {
+            return Some(format!("already solved with {}", correct.answer));
+        }
+        if let Some(earlier) = judged.iter().find(|s| s.answer == answer) {
+            return Some(format!("{} was already submitted and was {}", answer, earlier.outcome.name()));
+        }
+
+        // Numeric answers can also be ruled out by earlier too high/too low verdicts
+        let number = answer.parse::<i64>().ok()?;
+        for earlier in &judged {
+            let bound = match earlier.answer.parse::<i64>() {
+                Ok(bound) => bound,
+                Err(_) => continue
+            };
+            match earlier.outcome {
+                Outcome::TooHigh if number >= bound => return Some(format!("{} was too high, so {} is too", bound, number)),
+                Outcome::TooLow if number <= bound => return Some(format!("{} was too low, so {} is too", bound, number)),
+                _ => ()
+            }
+        }
+        None
+    }
+
+    pub fn record(&mut self, year: usize, day: usize, part: usize, answer: &str, outcome: Outcome) {
+        self.submissions.push(Submission { year, day, part, outcome, answer: answer.to_string() });
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let contents = self.submissions.iter()
+            .map(|s| format!("{} {} {} {} {}\n", s.year, s.day, s.part, s.outcome.name(), escape(&s.answer)))
+            .collect::<String>();
+        fs::write(&self.path, contents)?;
+        Ok(())
+    }
+}
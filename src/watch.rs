@@ -0,0 +1,92 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+// How often the watched files are checked
+pub const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+// Notices files changing by polling their modification times, which needs nothing from the OS
+// and is plenty for a handful of files. Files that don't exist yet count as changed once they show up
+pub struct Watcher {
+    files: Vec<(PathBuf, Option<SystemTime>)>
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+impl Watcher {
+    pub fn new(paths: impl IntoIterator<Item=PathBuf>) -> Watcher {
+        Watcher { files: paths.into_iter().map(|path| { let time = modified(&path); (path, time) }).collect() }
+    }
+
+    // Files that changed since the last look, in the order they were given
+    pub fn changed(&mut self) -> Vec<PathBuf> {
+        let mut changed = vec![];
+        for (path, time) in &mut self.files {
+            let now = modified(path);
+            if now != *time {
+                *time = now;
+                changed.push(path.clone());
+            }
+        }
+        changed
+    }
+
+    // Blocks until something changes. Editors tend to write a file in several goes,
+    // so this waits for one more quiet interval before reporting it
+    pub fn wait(&mut self) -> Vec<PathBuf> {
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let mut changed = self.changed();
+            if changed.is_empty() { continue; }
+
+            loop {
+                thread::sleep(POLL_INTERVAL);
+                let more = self.changed();
+                if more.is_empty() { break; }
+                changed.extend(more.into_iter().filter(|p| !changed.contains(p)).collect::<Vec<_>>());
+            }
+            return changed;
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Line<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str)
+}
+
+// Line by line, for answers like day 10's that span several lines.
+// A longest common subsequence is quadratic, but answers are never more than a few lines
+pub fn diff<'a>(old: &'a str, new: &'a str) -> Vec<Line<'a>> {
+    let (old, new) = (old.lines().collect::<Vec<_>>(), new.lines().collect::<Vec<_>>());
+
+    // common[i][j] is how many lines old[i..] and new[j..] have in common
+    let mut common = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = if old[i] == new[j] { common[i + 1][j + 1] + 1 } else { common[i + 1][j].max(common[i][j + 1]) };
+        }
+    }
+
+    let mut lines = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            lines.push(Line::Same(old[i]));
+            i += 1;
+            j += 1;
+        } else if j == new.len() || (i < old.len() && common[i + 1][j] >= common[i][j + 1]) {
+            lines.push(Line::Removed(old[i]));
+            i += 1;
+        } else {
+            lines.push(Line::Added(new[j]));
+            j += 1;
+        }
+    }
+    lines
+}
@@ -0,0 +1,110 @@
+use std::fmt::Debug;
+use std::panic::{self, AssertUnwindSafe};
+
+pub use aoc18::random::Rng;
+
+// Values that are simpler than this one, simplest first, for cutting a failure down to size
+pub trait Shrink: Clone + Debug {
+    fn shrink(&self) -> Vec<Self>;
+}
+
+impl Shrink for usize {
+    fn shrink(&self) -> Vec<usize> {
+        let mut smaller = vec![0, self / 2, self.saturating_sub(1)];
+        smaller.retain(|n| n < self);
+        smaller.dedup();
+        smaller
+    }
+}
+
+impl Shrink for bool {
+    fn shrink(&self) -> Vec<bool> {
+        if *self { vec![false] } else { vec![] }
+    }
+}
+
+impl Shrink for char {
+    fn shrink(&self) -> Vec<char> {
+        vec![]
+    }
+}
+
+impl<T: Shrink> Shrink for Vec<T> {
+    fn shrink(&self) -> Vec<Vec<T>> {
+        let mut smaller = vec![];
+        // Big cuts first, then single elements, then simpler elements
+        let mut chunk = self.len() / 2;
+        while chunk > 0 {
+            for start in (0..self.len()).step_by(chunk) {
+                let mut v = self.clone();
+                v.drain(start..(start + chunk).min(self.len()));
+                smaller.push(v);
+            }
+            chunk /= 2;
+        }
+        for (i, item) in self.iter().enumerate() {
+            for simpler in item.shrink() {
+                let mut v = self.clone();
+                v[i] = simpler;
+                smaller.push(v);
+            }
+        }
+        smaller
+    }
+}
+
+impl Shrink for String {
+    fn shrink(&self) -> Vec<String> {
+        self.chars().collect::<Vec<_>>().shrink().into_iter().map(|chars| chars.into_iter().collect()).collect()
+    }
+}
+
+impl<A: Shrink, B: Shrink> Shrink for (A, B) {
+    fn shrink(&self) -> Vec<(A, B)> {
+        let firsts = self.0.shrink().into_iter().map(|a| (a, self.1.clone()));
+        firsts.chain(self.1.shrink().into_iter().map(|b| (self.0.clone(), b))).collect()
+    }
+}
+
+impl<A: Shrink, B: Shrink, C: Shrink> Shrink for (A, B, C) {
+    fn shrink(&self) -> Vec<(A, B, C)> {
+        ((self.0.clone(), self.1.clone()), self.2.clone()).shrink().into_iter()
+            .map(|((a, b), c)| (a, b, c))
+            .collect()
+    }
+}
+
+// Passes if both agree, for comparing a solution against the obvious slow way
+pub fn same<T: PartialEq + Debug>(optimized: T, naive: T) -> Result<(), String> {
+    if optimized == naive { Ok(()) } else { Err(format!("got {:?} but the naive way gives {:?}", optimized, naive)) }
+}
+
+fn holds<T>(property: &impl Fn(&T) -> Result<(), String>, value: &T) -> Result<(), String> {
+    panic::catch_unwind(AssertUnwindSafe(|| property(value))).unwrap_or_else(|payload| {
+        let message = payload.downcast_ref::<String>().cloned()
+            .or_else(|| payload.downcast_ref::<&str>().map(|m| m.to_string()))
+            .unwrap_or_default();
+        Err(format!("panicked: {}", message))
+    })
+}
+
+// Tries the property on `cases` generated values and panics with the smallest failing value it can
+// shrink down to. The seed comes from $AOC_SEED when set, so a failure can be replayed
+pub fn check<T: Shrink>(cases: usize, mut generate: impl FnMut(&mut Rng) -> T, property: impl Fn(&T) -> Result<(), String>) {
+    let seed = std::env::var("AOC_SEED").ok().and_then(|s| s.parse().ok()).unwrap_or(2018);
+    let mut rng = Rng::new(seed);
+
+    for case in 0..cases {
+        let value = generate(&mut rng);
+        let mut failure = match holds(&property, &value) {
+            Ok(()) => continue,
+            Err(message) => (value, message)
+        };
+
+        // Keep taking the first simpler value that still fails, until none do
+        while let Some(found) = failure.0.shrink().into_iter().find_map(|v| holds(&property, &v).err().map(|m| (v, m))) {
+            failure = found;
+        }
+        panic!("case {} with seed {} fails, smallest failing value is {:?}: {}", case, seed, failure.0, failure.1);
+    }
+}
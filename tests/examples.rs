@@ -0,0 +1,21 @@
+use aoc18::examples;
+use aoc18::registry::Registry;
+
+// Goes through the registry so new days are covered as soon as they exist
+#[test]
+fn every_day_solves_its_examples() {
+    let mut failures = vec![];
+    for solver in Registry::new(2018).iter() {
+        if solver.examples().is_empty() {
+            failures.push(format!("day {} has no examples", solver.day()));
+        }
+
+        for check in examples::check(solver).into_iter().filter(|check| !check.passed()) {
+            failures.push(match check.answer {
+                Ok(answer) => format!("day {} example {} part {}: expected {:?}, got {:?}", solver.day(), check.example, check.part, check.expected, answer),
+                Err(e) => format!("day {} example {} part {}: expected {:?}, got error: {}", solver.day(), check.example, check.part, check.expected, e)
+            });
+        }
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
@@ -0,0 +1,153 @@
+use std::fs;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use indicatif::ProgressBar;
+
+use aoc18::error::{ErrorKind, Result};
+use aoc18::fetch::{self, Fetcher, HttpFetcher};
+use aoc18::inputs::InputCache;
+
+mod common;
+use common::{stub_server, temp_path};
+
+fn fetcher(url: &str, retries: usize) -> HttpFetcher {
+    HttpFetcher::new(Some("cafebabe".to_string()))
+        .with_base_url(url)
+        .with_retries(retries, Duration::from_millis(1))
+}
+
+#[test]
+fn fetches_input_with_session_and_user_agent() {
+    let (url, server) = stub_server(vec![(200, "+1\n-2\n")]);
+    assert_eq!(fetcher(&url, 0).fetch(2018, 1).unwrap(), "+1\n-2\n");
+
+    let requests = server.join().unwrap();
+    let request = requests[0].to_lowercase();
+    assert!(request.starts_with("get /2018/day/1/input "));
+    assert!(request.contains("cookie: session=cafebabe"));
+    assert!(request.contains(&format!("user-agent: {}", fetch::USER_AGENT.to_lowercase())));
+}
+
+#[test]
+fn retries_server_errors() {
+    let (url, server) = stub_server(vec![(500, "oops"), (503, "busy"), (200, "abcdef\n")]);
+    assert_eq!(fetcher(&url, 2).fetch(2018, 2).unwrap(), "abcdef\n");
+    assert_eq!(server.join().unwrap().len(), 3);
+}
+
+#[test]
+fn gives_up_after_retries() {
+    let (url, server) = stub_server(vec![(502, "down"), (502, "down")]);
+    match fetcher(&url, 1).fetch(2018, 3).unwrap_err().kind {
+        ErrorKind::Fetch { status: Some(502), .. } => (),
+        kind => panic!("unexpected error: {}", kind)
+    }
+    assert_eq!(server.join().unwrap().len(), 2);
+}
+
+#[test]
+fn rejects_login_page_without_retrying() {
+    let (url, server) = stub_server(vec![(400, "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n")]);
+    match fetcher(&url, 3).fetch(2018, 4).unwrap_err().kind {
+        ErrorKind::Fetch { status: Some(400), ref reason } => assert!(reason.contains("session")),
+        kind => panic!("unexpected error: {}", kind)
+    }
+    assert_eq!(server.join().unwrap().len(), 1);
+}
+
+#[test]
+fn rejects_locked_puzzles() {
+    let (url, _) = stub_server(vec![(404, "Please don't repeatedly request this endpoint before it unlocks!")]);
+    match fetcher(&url, 3).fetch(2018, 25).unwrap_err().kind {
+        ErrorKind::Fetch { status: Some(404), .. } => (),
+        kind => panic!("unexpected error: {}", kind)
+    }
+}
+
+#[test]
+fn rejects_html_and_empty_bodies() {
+    let (url, _) = stub_server(vec![(200, "<!DOCTYPE html>\n<html></html>")]);
+    assert!(fetcher(&url, 0).fetch(2018, 5).is_err());
+
+    let (url, _) = stub_server(vec![(200, "")]);
+    assert!(fetcher(&url, 0).fetch(2018, 5).is_err());
+
+    assert!(fetch::check_input("dabAcCaCBAcCcaDA\n").is_ok());
+}
+
+#[test]
+fn needs_a_session() {
+    match HttpFetcher::new(None).fetch(2018, 1).unwrap_err().kind {
+        ErrorKind::NoSession => (),
+        kind => panic!("unexpected error: {}", kind)
+    }
+}
+
+struct CountingFetcher {
+    calls: AtomicUsize,
+    response: fn() -> Result<String>
+}
+
+impl Fetcher for &'static CountingFetcher {
+    fn fetch(&self, _year: usize, _day: usize) -> Result<String> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        (self.response)()
+    }
+}
+
+#[test]
+fn cache_stores_only_good_inputs() {
+    static GOOD: CountingFetcher = CountingFetcher { calls: AtomicUsize::new(0), response: || Ok("1\n".to_string()) };
+    static BAD: CountingFetcher = CountingFetcher { calls: AtomicUsize::new(0), response: || Err(aoc18::error::Error::fetch(Some(400), "log in")) };
+
+    let dir = temp_path("cache");
+    let bad = InputCache::new(&dir, 2018, false, Box::new(&BAD));
+    assert!(bad.get(1, &ProgressBar::hidden()).is_err());
+    assert!(!bad.is_cached(1));
+
+    let good = InputCache::new(&dir, 2018, false, Box::new(&GOOD));
+    assert_eq!(good.get(1, &ProgressBar::hidden()).unwrap(), "1\n");
+    assert_eq!(good.get(1, &ProgressBar::hidden()).unwrap(), "1\n");
+    assert_eq!(GOOD.calls.load(Ordering::SeqCst), 1);
+
+    let offline = InputCache::new(&dir, 2018, true, Box::new(&GOOD));
+    match offline.get(2, &ProgressBar::hidden()).unwrap_err().kind {
+        ErrorKind::Offline => (),
+        kind => panic!("unexpected error: {}", kind)
+    }
+    assert_eq!(GOOD.calls.load(Ordering::SeqCst), 1);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn flat_inputs_only_move_under_2018_when_adopted() {
+    static UNUSED: CountingFetcher = CountingFetcher { calls: AtomicUsize::new(0), response: || Ok(String::new()) };
+
+    let dir = temp_path("flat-cache");
+    fs::create_dir_all(dir.join("2018")).unwrap();
+    fs::write(dir.join("3.txt"), "#1 @ 1,3: 4x4\n").unwrap();
+    fs::write(dir.join("5.txt"), "aA\n").unwrap();
+    fs::write(dir.join("2018").join("5.txt"), "bB\n").unwrap();
+
+    let later = InputCache::new(&dir, 2019, true, Box::new(&UNUSED));
+    assert!(later.legacy().unwrap().is_empty());
+    assert!(later.adopt_legacy().unwrap().is_empty());
+    assert!(dir.join("3.txt").exists());
+
+    let cache = InputCache::new(&dir, 2018, true, Box::new(&UNUSED));
+    assert!(!cache.is_cached(3));
+    assert!(dir.join("3.txt").exists());
+    assert_eq!(cache.legacy().unwrap().len(), 2);
+
+    let adopted = cache.adopt_legacy().unwrap();
+    assert_eq!(adopted.iter().map(|input| input.day).collect::<Vec<_>>(), [3]);
+    assert_eq!(cache.get(3, &ProgressBar::hidden()).unwrap(), "#1 @ 1,3: 4x4\n");
+    assert!(!dir.join("3.txt").exists());
+    // The year's own copy wins
+    assert_eq!(cache.get(5, &ProgressBar::hidden()).unwrap(), "bB\n");
+    assert!(dir.join("5.txt").exists());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
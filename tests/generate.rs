@@ -0,0 +1,47 @@
+use aoc18::params::Params;
+use aoc18::registry::Registry;
+
+#[test]
+fn every_solved_day_has_a_generator() {
+    for solver in Registry::new(2018).iter() {
+        assert!(solver.generator().is_some(), "day {} has no generator", solver.day());
+    }
+}
+
+#[test]
+fn the_same_seed_makes_the_same_input() {
+    for solver in Registry::new(2018).iter() {
+        let generator = solver.generator().unwrap();
+        assert_eq!(generator.generate(20, 5), generator.generate(20, 5), "day {}", solver.day());
+    }
+}
+
+// Small ones, the real sizes are too slow for a debug build. None go past the real size, day 14's
+// part 1 makes as many recipes as its digits say
+#[test]
+fn generated_inputs_have_answers() {
+    for solver in Registry::new(2018).iter() {
+        let generator = solver.generator().unwrap();
+        for seed in 1..=3 {
+            for &size in &[2, 12] {
+                let size = size.min(generator.default_size);
+                let input = generator.generate(size, seed);
+                let parsed = solver.parse(&input, &Params::default())
+                    .unwrap_or_else(|e| panic!("day {} seed {} size {}: {}", solver.day(), seed, size, e));
+                for (part, answer) in [(1, solver.part1(parsed.as_ref())), (2, solver.part2(parsed.as_ref()))] {
+                    if let Err(e) = answer {
+                        panic!("day {} seed {} size {} part {}: {}", solver.day(), seed, size, part, e);
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Seed 2 has a guard whose shift starts late enough to leave fewer minutes than naps, which
+// used to keep rolling for a minute that wasn't there
+#[test]
+fn day4_naps_fit_in_short_shifts() {
+    let generator = Registry::new(2018).get(4).and_then(|solver| solver.generator()).unwrap();
+    assert!(generator.generate(20000, 2).lines().count() > 20000);
+}
@@ -0,0 +1,176 @@
+// The fast ways the days do things against the obvious slow ways, on lots of small random inputs
+use std::collections::BTreeSet;
+
+use aoc18::days::y2018::{day1, day11, day12, day5, day9};
+use aoc18::params::Params;
+use aoc18::solver::AnySolver;
+
+mod common;
+use common::prop::{self, Rng};
+
+fn solve(solver: &dyn AnySolver, input: &str, params: &[(&str, u64)], part: usize) -> Result<String, String> {
+    let parsed = solver.parse(input, &Params::from_pairs(params)).map_err(|e| e.to_string())?;
+    let answer = if part == 1 { solver.part1(parsed.as_ref()) } else { solver.part2(parsed.as_ref()) };
+    answer.map_err(|e| e.to_string())
+}
+
+// Goes round and round, giving up after enough passes that any repeat would have shown up
+fn naive_first_repeat(changes: &[isize]) -> Option<isize> {
+    let mut seen = BTreeSet::new();
+    let mut frequency = 0;
+    seen.insert(frequency);
+    for change in changes.iter().cycle().take(changes.len() * 200) {
+        frequency += change;
+        if !seen.insert(frequency) { return Some(frequency); }
+    }
+    None
+}
+
+#[test]
+fn day1_repeat_matches_going_round() {
+    prop::check(300, |rng: &mut Rng| (0..rng.range(1, 8)).map(|_| rng.range(0, 10)).collect::<Vec<_>>(), |changes| {
+        let changes = changes.iter().map(|&c| c as isize - 5).collect::<Vec<_>>();
+        let input = changes.iter().map(|c| format!("{:+}\n", c)).collect::<String>();
+        let expected = match naive_first_repeat(&changes) {
+            Some(frequency) => frequency.to_string(),
+            None => "day 1: no solution for this input".to_string()
+        };
+        prop::same(solve(&day1::Day1, &input, &[], 2).unwrap_or_else(|e| e), expected)
+    });
+}
+
+// Removes the first reacting pair until there are none left
+fn naive_reacted_length(polymer: &str) -> usize {
+    let mut units = polymer.chars().collect::<Vec<_>>();
+    while let Some(i) = (1..units.len()).find(|&i| units[i - 1] != units[i] && units[i - 1].eq_ignore_ascii_case(&units[i])) {
+        units.drain(i - 1..=i);
+    }
+    units.len()
+}
+
+#[test]
+fn day5_reaction_matches_removing_pairs_one_at_a_time() {
+    prop::check(300, |rng: &mut Rng| (0..rng.range(0, 40)).map(|_| rng.pick(&['a', 'A', 'b', 'B', 'c', 'C'])).collect::<String>(), |polymer| {
+        prop::same(solve(&day5::Day5, polymer, &[], 1)?, naive_reacted_length(polymer).to_string())?;
+
+        let shortest = ('a'..='z')
+            .map(|unit| naive_reacted_length(&polymer.replace([unit, unit.to_ascii_uppercase()], "")))
+            .min()
+            .unwrap();
+        prop::same(solve(&day5::Day5, polymer, &[], 2)?, shortest.to_string())
+    });
+}
+
+// Inserting into and removing from a Vec at the current marble's index, like the puzzle describes it
+fn naive_winner(players: usize, last_marble: usize) -> usize {
+    let mut circle = vec![0];
+    let mut current = 0;
+    let mut scores = vec![0; players];
+    for marble in 1..=last_marble {
+        if marble % 23 == 0 {
+            let removed = (current + circle.len() - 7 % circle.len()) % circle.len();
+            scores[(marble - 1) % players] += marble + circle.remove(removed);
+            current = removed % circle.len();
+        } else {
+            current = (current + 1) % circle.len() + 1;
+            circle.insert(current, marble);
+        }
+    }
+    scores.into_iter().max().unwrap()
+}
+
+#[test]
+fn day9_circle_matches_a_vec() {
+    let generate = |rng: &mut Rng| (rng.range(1, 10), rng.range(0, 300), rng.range(1, 3));
+    prop::check(300, generate, |&(players, marbles, factor)| {
+        if players == 0 { return Ok(()); }
+        let input = format!("{} players; last marble is worth {} points", players, marbles);
+
+        prop::same(solve(&day9::Day9, &input, &[], 1)?, naive_winner(players, marbles).to_string())?;
+        prop::same(solve(&day9::Day9, &input, &[("marble_factor", factor as u64)], 2)?, naive_winner(players, marbles * factor).to_string())
+    });
+}
+
+// Adding up every cell of the square, 0-based
+fn naive_power(serial: usize, x: usize, y: usize, size: usize) -> isize {
+    (y..y + size).flat_map(|y| (x..x + size).map(move |x| day11::power(x, y, serial))).sum()
+}
+
+fn best_power(serial: usize, grid_size: usize, sizes: &[usize]) -> isize {
+    let mut best = isize::MIN;
+    for &size in sizes {
+        for y in 0..=grid_size - size {
+            for x in 0..=grid_size - size {
+                best = best.max(naive_power(serial, x, y, size));
+            }
+        }
+    }
+    best
+}
+
+#[test]
+fn day11_sum_grid_matches_adding_up_squares() {
+    prop::check(200, |rng: &mut Rng| (rng.range(0, 1000), rng.range(3, 10)), |&(serial, grid_size)| {
+        // Too small for a 3x3 square, which the day refuses
+        if grid_size < 3 { return Ok(()); }
+        let params = [("grid_size", grid_size as u64)];
+
+        // Ties can go to any square, so only how much power it has is compared
+        let part1 = solve(&day11::Day11, &serial.to_string(), &params, 1)?;
+        let corner = part1.split(',').map(|n| n.parse::<usize>().unwrap()).collect::<Vec<_>>();
+        prop::same(naive_power(serial, corner[0] - 1, corner[1] - 1, 3), best_power(serial, grid_size, &[3]))?;
+
+        let part2 = solve(&day11::Day11, &serial.to_string(), &params, 2)?;
+        let found = part2.split(',').map(|n| n.parse::<usize>().unwrap()).collect::<Vec<_>>();
+        if found[0] + found[2] - 1 > grid_size || found[1] + found[2] - 1 > grid_size {
+            return Err(format!("{} doesn't fit in the grid", part2));
+        }
+        prop::same(naive_power(serial, found[0] - 1, found[1] - 1, found[2]), best_power(serial, grid_size, &(1..=grid_size).collect::<Vec<_>>()))
+    });
+}
+
+fn pots(state: &[bool]) -> String {
+    state.iter().map(|&plant| if plant { '#' } else { '.' }).collect()
+}
+
+// Every pot that could change, every generation. Rules past the 32 patterns are ignored and missing ones grow nothing
+fn naive_plants(initial: &[bool], rules: &[bool], generations: usize) -> isize {
+    let mut plants = (0..initial.len() as isize).filter(|&i| initial[i as usize]).collect::<BTreeSet<_>>();
+    for _ in 0..generations {
+        let (first, last) = match (plants.iter().next(), plants.iter().next_back()) {
+            (Some(&first), Some(&last)) => (first, last),
+            _ => break
+        };
+        plants = (first - 2..=last + 2)
+            .filter(|i| {
+                let pattern = (0..5).filter(|j| plants.contains(&(i - 2 + j))).fold(0, |bits, j| bits | (1 << j));
+                rules.get(pattern).cloned().unwrap_or(false)
+            })
+            .collect();
+    }
+    plants.iter().sum()
+}
+
+#[test]
+fn day12_extrapolation_matches_simulating_every_generation() {
+    let generate = |rng: &mut Rng| {
+        let initial = (0..rng.range(0, 15)).map(|_| rng.chance(40)).collect::<Vec<_>>();
+        let rules = (0..32).map(|_| rng.chance(40)).collect::<Vec<_>>();
+        (initial, rules, rng.range(0, 150))
+    };
+    prop::check(200, generate, |(initial, rules, generations)| {
+        // Plants out of nowhere would fill the whole row, the puzzle never asks for that
+        if rules.first() == Some(&true) { return Ok(()); }
+
+        let rule_lines = (0..32)
+            .map(|pattern| {
+                let window = pots(&(0..5).map(|j| pattern & (1 << j) != 0).collect::<Vec<_>>());
+                format!("{} => {}", window, if rules.get(pattern).cloned().unwrap_or(false) { '#' } else { '.' })
+            })
+            .collect::<Vec<_>>();
+        let input = format!("initial state: {}\n\n{}", pots(initial), rule_lines.join("\n"));
+
+        prop::same(solve(&day12::Day12, &input, &[], 1)?, naive_plants(initial, rules, 20).to_string())?;
+        prop::same(solve(&day12::Day12, &input, &[("generations", *generations as u64)], 2)?, naive_plants(initial, rules, *generations).to_string())
+    });
+}
@@ -0,0 +1,116 @@
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+
+use indicatif::ProgressBar;
+
+use aoc18::answers::Answers;
+use aoc18::cancel;
+use aoc18::days::y2018::day7::Day7;
+use aoc18::error::{ErrorKind, Result};
+use aoc18::fetch::HttpFetcher;
+use aoc18::inputs::InputCache;
+use aoc18::params::Params;
+use aoc18::runner::{self, RunOptions};
+use aoc18::solver::Solver;
+
+mod common;
+use common::temp_path;
+
+// How many times part 2 went round for the "counted" input
+static COUNTED: AtomicUsize = AtomicUsize::new(0);
+
+struct Misbehaving;
+
+impl Solver for Misbehaving {
+    const DAY: usize = 99;
+    const TITLE: &'static str = "Misbehaving";
+
+    type Parsed = String;
+
+    fn parse(input: &str, _params: &Params) -> Result<String> {
+        if input == "panic in parse" { panic!("bad parse"); }
+        Ok(input.to_string())
+    }
+
+    fn part1(parsed: &String) -> Result<String> {
+        match parsed.as_str() {
+            "panic" => panic!("part 1 exploded with {}", parsed),
+            _ => Ok(parsed.len().to_string())
+        }
+    }
+
+    // Only ever stops by being cancelled, unless there's no input
+    fn part2(parsed: &String) -> Result<String> {
+        loop {
+            if parsed.is_empty() { return Ok(String::new()); }
+            cancel::check()?;
+            if parsed == "counted" { COUNTED.fetch_add(1, Ordering::SeqCst); }
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+}
+
+fn run(input: &str) -> runner::Report {
+    let path = temp_path("runner");
+    let inputs = InputCache::new(&path, 2018, true, Box::new(HttpFetcher::new(None)));
+    let answers = Answers::load(path.join("answers.txt")).unwrap();
+    let options = RunOptions { input: Some(input.to_string()), timeout: Some(Duration::from_millis(200)), ..Default::default() };
+    runner::run(&inputs, &Misbehaving, &answers, &options, &ProgressBar::hidden())
+}
+
+#[test]
+fn panics_are_reported() {
+    let report = run("panic");
+    let parts = report.parts.unwrap();
+    match parts[0].answer.as_ref().unwrap_err().kind {
+        ErrorKind::Panicked(ref message) => assert_eq!(message, "part 1 exploded with panic"),
+        ref kind => panic!("unexpected error: {}", kind)
+    }
+    assert_eq!(parts[0].answer.as_ref().unwrap_err().to_string(), "day 99: panicked: part 1 exploded with panic");
+
+    match run("panic in parse").parts.unwrap_err().kind {
+        ErrorKind::Panicked(ref message) => assert_eq!(message, "bad parse"),
+        ref kind => panic!("unexpected error: {}", kind)
+    }
+}
+
+#[test]
+fn hanging_parts_time_out() {
+    let report = run("abc");
+    let parts = report.parts.unwrap();
+    assert_eq!(parts[0].answer.as_ref().unwrap(), "3");
+    match parts[1].answer.as_ref().unwrap_err().kind {
+        ErrorKind::TimedOut(after) => assert!(after >= Duration::from_millis(200)),
+        ref kind => panic!("unexpected error: {}", kind)
+    }
+}
+
+#[test]
+fn errors_fail_the_report() {
+    assert!(!run("").failed());
+    assert!(run("panic").failed());
+    assert!(run("panic in parse").failed());
+    assert!(run("abc").failed());
+}
+
+#[test]
+fn timed_out_days_stop_running() {
+    run("counted");
+    thread::sleep(Duration::from_millis(50));
+    let stopped_at = COUNTED.load(Ordering::SeqCst);
+    assert!(stopped_at > 0);
+    thread::sleep(Duration::from_millis(100));
+    assert_eq!(COUNTED.load(Ordering::SeqCst), stopped_at);
+}
+
+#[test]
+fn only_changed_params_make_a_variant() {
+    let with = |pairs: &[(&str, u64)]| RunOptions { params: BTreeMap::from([(7, Params::from_pairs(pairs))]), ..Default::default() };
+    assert!(!with(&[]).is_variant(&Day7));
+    assert!(!with(&[("workers", 5), ("base_time", 60)]).is_variant(&Day7));
+    assert!(with(&[("workers", 5), ("base_time", 0)]).is_variant(&Day7));
+    assert!(with(&[("elves", 5)]).is_variant(&Day7));
+    assert!(RunOptions { input: Some("Step A must be finished before step B can begin.".to_string()), ..Default::default() }.is_variant(&Day7));
+}
@@ -0,0 +1,35 @@
+use std::fs;
+
+use aoc18::scaffold;
+
+mod common;
+use common::temp_path;
+
+#[test]
+fn finds_the_checkout_from_inside_it() {
+    let root = temp_path("checkout");
+    let nested = scaffold::days_dir(&root).join("y2018");
+    fs::create_dir_all(&nested).unwrap();
+    fs::write(root.join("Cargo.toml"), "").unwrap();
+
+    assert_eq!(scaffold::find_root(&nested), Some(root.clone()));
+    assert_eq!(scaffold::find_root(&root), Some(root.clone()));
+
+    // Any other crate doesn't have days to add to
+    fs::remove_dir_all(scaffold::days_dir(&root)).unwrap();
+    fs::create_dir_all(root.join("src")).unwrap();
+    assert_eq!(scaffold::find_root(root.join("src")), None);
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn new_days_go_in_their_year() {
+    let root = temp_path("new-day");
+    let path = scaffold::create(scaffold::days_dir(&root), 2019, 3, "Crossed Wires").unwrap();
+    assert_eq!(path, root.join("src").join("days").join("y2019").join("day3.rs"));
+    assert!(fs::read_to_string(&path).unwrap().contains("solver!(Day3, 3, \"Crossed Wires\""));
+    assert_eq!(scaffold::create(scaffold::days_dir(&root), 2019, 3, "Again").unwrap_err().kind(), std::io::ErrorKind::AlreadyExists);
+
+    fs::remove_dir_all(&root).unwrap();
+}
@@ -0,0 +1,92 @@
+use std::fs;
+use std::time::Duration;
+
+use aoc18::error::ErrorKind;
+use aoc18::fetch::HttpFetcher;
+use aoc18::submit::{self, History, Outcome, Submitter};
+
+mod common;
+use common::{stub_server, temp_path};
+
+const RIGHT: &str = "<main><article><p>That's the right answer!  You are <em>one gold star</em> closer to fixing the time stream.</p></article></main>";
+const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too high.  Please wait one minute before trying again.</p></article></main>";
+const TOO_LOW: &str = "<main><article><p>That's not the right answer; your answer is too low.</p></article></main>";
+const TOO_RECENT: &str = "<main><article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.</p></article></main>";
+const WRONG_LEVEL: &str = "<main><article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article></main>";
+
+#[test]
+fn parses_responses() {
+    assert_eq!(submit::parse_response(RIGHT).unwrap(), Outcome::Correct);
+    assert_eq!(submit::parse_response(TOO_HIGH).unwrap(), Outcome::TooHigh);
+    assert_eq!(submit::parse_response(TOO_LOW).unwrap(), Outcome::TooLow);
+    assert_eq!(submit::parse_response(TOO_RECENT).unwrap(), Outcome::RateLimited { wait: Some(Duration::from_secs(65)) });
+    assert_eq!(submit::parse_response(WRONG_LEVEL).unwrap(), Outcome::AlreadySolved);
+    assert!(submit::parse_response("<html>something else entirely</html>").is_err());
+}
+
+#[test]
+fn posts_answer_to_server() {
+    let (url, server) = stub_server(vec![(200, TOO_LOW)]);
+    let submitter = HttpFetcher::new(Some("cafebabe".to_string())).with_base_url(&url);
+    assert_eq!(submitter.submit(2018, 7, 2, "1234").unwrap(), Outcome::TooLow);
+
+    let requests = server.join().unwrap();
+    let request = requests[0].to_lowercase();
+    assert!(request.starts_with("post /2018/day/7/answer "));
+    assert!(request.contains("cookie: session=cafebabe"));
+    assert!(request.ends_with("level=2&answer=1234"));
+}
+
+#[test]
+fn server_errors_are_not_retried() {
+    let (url, server) = stub_server(vec![(500, "oops")]);
+    let submitter = HttpFetcher::new(Some("cafebabe".to_string())).with_base_url(&url);
+    match submitter.submit(2018, 1, 1, "3").unwrap_err().kind {
+        ErrorKind::Fetch { status: Some(500), .. } => (),
+        kind => panic!("unexpected error: {}", kind)
+    }
+    assert_eq!(server.join().unwrap().len(), 1);
+}
+
+#[test]
+fn history_refuses_known_answers() {
+    let path = temp_path("submissions.txt");
+    let mut history = History::load(&path).unwrap();
+    history.record(2018, 1, 1, "500", Outcome::TooHigh);
+    history.record(2018, 1, 1, "100", Outcome::TooLow);
+    history.record(2018, 1, 1, "abc", Outcome::Wrong);
+    history.record(2018, 1, 1, "300", Outcome::RateLimited { wait: None });
+    history.save().unwrap();
+
+    let history = History::load(&path).unwrap();
+    assert!(history.refuse(2018, 1, 1, "500").is_some());
+    assert!(history.refuse(2018, 1, 1, "700").is_some());
+    assert!(history.refuse(2018, 1, 1, "50").is_some());
+    assert!(history.refuse(2018, 1, 1, "abc").is_some());
+    // Rate limited attempts were never judged
+    assert!(history.refuse(2018, 1, 1, "300").is_none());
+    assert!(history.refuse(2018, 1, 2, "500").is_none());
+
+    let mut history = history;
+    history.record(2018, 1, 2, "line one\nline two", Outcome::Correct);
+    history.save().unwrap();
+    let history = History::load(&path).unwrap();
+    assert!(history.refuse(2018, 1, 2, "anything").is_some());
+    assert_eq!(history.submissions(2018, 1, 2).next().unwrap().answer, "line one\nline two");
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn history_from_before_years_is_2018s() {
+    let path = temp_path("submissions.txt");
+    fs::write(&path, "3 1 too-high 500\n2019 3 1 correct 120\n").unwrap();
+
+    let history = History::load(&path).unwrap();
+    assert_eq!(history.refuse(2018, 3, 1, "500").unwrap(), "500 was already submitted and was too-high");
+    assert!(history.refuse(2018, 3, 1, "120").is_none());
+    assert!(history.refuse(2019, 3, 1, "90").is_some());
+    assert!(history.refuse(2020, 3, 1, "500").is_none());
+
+    fs::remove_file(&path).unwrap();
+}